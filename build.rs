@@ -1,16 +1,85 @@
 //! Install Rollup.js to bundle all material-web components into one JS file we can include using our `UseMaterialWebComponents` component.
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use sha2::{Digest, Sha384};
 use std::{
+    collections::{BTreeMap, HashSet},
     env,
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io,
+    path::{Path, PathBuf},
     process::Command,
 };
 
 const IMPORTS_JS_FILE_NAME: &str = "imports.js";
 const OUTPUT_BUNDLE_FILE_NAME: &str = "output_bundle.js";
+const EXTERNAL_BUNDLE_RS_FILE_NAME: &str = "external_bundle.rs";
+const MODULES_DIR_NAME: &str = "modules";
+/// With lazy loading, the module importing features that have no Rust module. It's loaded with the page.
+const EAGER_MODULE_NAME: &str = "eager";
+const SYMBOLS_RS_FILE_NAME: &str = "symbols.rs";
+/// The Material Symbols fonts `UseMaterialWebComponents` loads. Their `.codepoints` files, listing every icon's name and codepoint,
+/// are downloaded from [google/material-design-icons](https://github.com/google/material-design-icons/tree/master/variablefont).
+const SYMBOL_FONTS: [&str; 3] = [
+    "MaterialSymbolsOutlined",
+    "MaterialSymbolsRounded",
+    "MaterialSymbolsSharp",
+];
+const CODEPOINTS_URL: &str =
+    "https://raw.githubusercontent.com/google/material-design-icons/master/variablefont";
+/// Path of a `.codepoints` file to generate `Symbol` from instead of the downloaded ones, e.g. for offline builds.
+const CODEPOINTS_ENV: &str = "LEPTOS_MATERIAL_CODEPOINTS";
+/// Overrides the directory the `external-bundle` file is written to.
+const BUNDLE_DIR_ENV: &str = "LEPTOS_MATERIAL_BUNDLE_DIR";
+/// Overrides the URL path the `external-bundle` file is served from.
+const BUNDLE_URL_ENV: &str = "LEPTOS_MATERIAL_BUNDLE_URL";
+
+/// A cargo feature providing components. See `src/components/manifest.rs`.
+struct Component {
+    feature: &'static str,
+    modules: &'static [&'static str],
+    tags: &'static [&'static str],
+    rust_module: Option<&'static str>,
+}
+
+macro_rules! component_manifest {
+    ($(
+        $feature:literal => {
+            modules: [$($material_module:literal),* $(,)?],
+            tags: [$($tag:literal),* $(,)?],
+            $(rust_module: $rust_module:ident,)?
+        }
+    ),* $(,)?) => {
+        const COMPONENTS: &[Component] = &[$(
+            Component {
+                feature: $feature,
+                modules: &[$($material_module),*],
+                tags: &[$($tag),*],
+                rust_module: component_manifest!(@rust_module $($rust_module)?),
+            }
+        ),*];
+    };
+    (@rust_module) => { None };
+    (@rust_module $rust_module:ident) => { Some(stringify!($rust_module)) };
+}
+
+include!("src/components/manifest.rs");
+
 fn main() {
     // Only re-run if new features added.
     println!("cargo::rerun-if-changed=Cargo.toml,build.rs");
+    println!("cargo::rerun-if-changed=src/components/manifest.rs");
+    for var in [
+        BUNDLE_DIR_ENV,
+        BUNDLE_URL_ENV,
+        CODEPOINTS_ENV,
+        "LEPTOS_SITE_ROOT",
+        "LEPTOS_SITE_PKG_DIR",
+    ] {
+        println!("cargo::rerun-if-env-changed={}", var);
+    }
+    if is_feature_enabled("icon") {
+        write_symbols();
+    }
     let imports_file_path = format!("{}/{}", env::var("OUT_DIR").unwrap(), IMPORTS_JS_FILE_NAME);
     println!("cargo::warning={:?}", imports_file_path);
     let mut imports_file: File = OpenOptions::new()
@@ -35,55 +104,361 @@ fn main() {
 
     // Import typography stylesheet
     add_import("typography/md-typescale-styles", &mut imports_file);
-    // Import components
-    if is_feature_enabled("checkbox") {
-        add_import("checkbox/checkbox", &mut imports_file);
-    }
-    if is_feature_enabled("textfield") {
-        add_import("textfield/filled-text-field", &mut imports_file);
-        add_import("textfield/outlined-text-field", &mut imports_file);
-    }
-    if is_feature_enabled("icon") {
-        add_import("icon/icon", &mut imports_file);
-    }
-    if is_feature_enabled("button") {
-        add_import("button/outlined-button", &mut imports_file);
-        add_import("button/elevated-button", &mut imports_file);
-        add_import("button/filled-button", &mut imports_file);
-        add_import("button/filled-tonal-button", &mut imports_file);
-        add_import("button/text-button", &mut imports_file);
+    // Import components. With lazy loading, each component gets its own ES module that is imported the first time it's rendered.
+    let lazy_loading = is_feature_enabled("lazy-loading");
+    let modules_src_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("modules-src");
+    let modules_out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join(MODULES_DIR_NAME);
+    let mut module_inputs = Vec::new();
+    let mut eager_modules = Vec::new();
+    if lazy_loading {
+        let _ = fs::remove_dir_all(&modules_out_dir);
+        fs::create_dir_all(&modules_src_dir).unwrap();
+        fs::create_dir_all(&modules_out_dir).unwrap();
     }
-    if is_feature_enabled("iconbutton") {
-        add_import("iconbutton/icon-button", &mut imports_file);
+    let enabled_components: Vec<&Component> = COMPONENTS
+        .iter()
+        .filter(|component| is_feature_enabled(component.feature))
+        .collect();
+    check_rendered_tags_are_imported(&enabled_components);
+    for component in enabled_components
+        .iter()
+        .filter(|component| !component.modules.is_empty())
+    {
+        if lazy_loading && component.rust_module.is_some() {
+            let module_path = modules_src_dir.join(format!("{}.js", component.feature));
+            let mut module_file = File::create(&module_path)
+                .unwrap_or_else(|_| panic!("Unable to create {:?}", module_path));
+            for module in component.modules {
+                add_import(module, &mut module_file);
+            }
+            module_inputs.push(format!("-i {}={}", component.feature, module_path.display()));
+        } else if lazy_loading {
+            // Features without a Rust module have no component to import them on first render, so they're loaded with the page.
+            // They're built with the lazy modules rather than into the bundle, so elements both import, such as `md-ripple`,
+            // end up in one shared chunk and are only defined once.
+            eager_modules.extend(component.modules);
+        } else {
+            for module in component.modules {
+                add_import(module, &mut imports_file);
+            }
+        }
     }
-    if is_feature_enabled("elevation") {
-        add_import("elevation/elevation", &mut imports_file);
-    }
-    if is_feature_enabled("progress") {
-        add_import("progress/circular-progress", &mut imports_file);
-        add_import("progress/linear-progress", &mut imports_file);
-    }
-    if is_feature_enabled("select") {
-        add_import("select/filled-select", &mut imports_file);
-        add_import("select/outlined-select", &mut imports_file);
-        add_import("select/select-option", &mut imports_file);
-    }
-    if is_feature_enabled("chips") {
-        add_import("chips/assist-chip", &mut imports_file);
-        add_import("chips/filter-chip", &mut imports_file);
-        add_import("chips/suggestion-chip", &mut imports_file);
-        add_import("chips/input-chip", &mut imports_file);
+    if !eager_modules.is_empty() {
+        let module_path = modules_src_dir.join(format!("{}.js", EAGER_MODULE_NAME));
+        let mut module_file = File::create(&module_path)
+            .unwrap_or_else(|_| panic!("Unable to create {:?}", module_path));
+        for module in eager_modules {
+            add_import(module, &mut module_file);
+        }
+        module_inputs.push(format!("-i {}={}", EAGER_MODULE_NAME, module_path.display()));
     }
     add_typescale_styles(&mut imports_file);
     assert!(imports_file.sync_all().is_ok());
     // Node modules are installed in the CARGO_MANIFEST_DIR instead of the OUT_DIR to prevent completely re-installing @material/web
     // each time the build script is run.
+    let node_resolve = format!(
+        r#"-p 'node-resolve={{modulePaths: ["{}/node_modules/"]}}'"#,
+        env::var("CARGO_MANIFEST_DIR").unwrap()
+    );
     let run_rollup = run_command(&format!(
-        r#"npx rollup -p 'node-resolve={{modulePaths: ["{}/node_modules/"]}}' {} -o {} --format iife"#,
-        env::var("CARGO_MANIFEST_DIR").unwrap(), imports_file_path, output_path
+        "npx rollup {} {} -o {} --format iife",
+        node_resolve, imports_file_path, output_path
     ))
     .unwrap();
     assert!(run_rollup.success());
+    if !module_inputs.is_empty() {
+        // Code shared between components is split into chunks, so it's only downloaded once.
+        let run_rollup_modules = run_command(&format!(
+            "npx rollup {} {} -d {} --format es --chunkFileNames 'chunks/[name]-[hash].js'",
+            node_resolve,
+            module_inputs.join(" "),
+            modules_out_dir.display()
+        ))
+        .unwrap();
+        assert!(run_rollup_modules.success());
+    }
+    if is_feature_enabled("external-bundle") {
+        write_external_bundle(&output_path, lazy_loading.then_some(&modules_out_dir));
+    }
+}
+
+/// Copy the bundle into the site's static files under a content-hashed name, and generate the constants
+/// `UseMaterialWebComponents` needs to link to it.
+/// By default the bundle is placed in cargo-leptos' `LEPTOS_SITE_ROOT/LEPTOS_SITE_PKG_DIR`.
+/// With lazy loading, the component modules are copied to a content-hashed directory next to it.
+fn write_external_bundle(bundle_path: &str, modules_dir: Option<&PathBuf>) {
+    let bundle = fs::read(bundle_path).expect("Unable to read generated bundle");
+    let digest = Sha384::digest(&bundle);
+    let integrity = format!("sha384-{}", BASE64.encode(digest));
+    let file_name = format!("leptos-material-{}.js", short_hash(&digest));
+
+    let pkg_dir = env::var("LEPTOS_SITE_PKG_DIR").unwrap_or_else(|_| "pkg".into());
+    let bundle_dir = env::var(BUNDLE_DIR_ENV).map(PathBuf::from).unwrap_or_else(|_| {
+        let site_root = env::var("LEPTOS_SITE_ROOT").unwrap_or_else(|_| "target/site".into());
+        PathBuf::from(site_root).join(&pkg_dir)
+    });
+    // Relative paths are relative to where cargo was invoked, which is the workspace root under cargo-leptos.
+    let bundle_dir = if bundle_dir.is_relative() {
+        env::var("PWD")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| env::current_dir().unwrap())
+            .join(bundle_dir)
+    } else {
+        bundle_dir
+    };
+    fs::create_dir_all(&bundle_dir)
+        .unwrap_or_else(|_| panic!("Unable to create bundle directory {:?}", bundle_dir));
+    fs::write(bundle_dir.join(&file_name), &bundle)
+        .unwrap_or_else(|_| panic!("Unable to write bundle to {:?}", bundle_dir));
+
+    let url_prefix = env::var(BUNDLE_URL_ENV).unwrap_or_else(|_| format!("/{}", pkg_dir));
+    let url_prefix = url_prefix.trim_end_matches('/');
+    let mut constants = format!(
+        "pub const BUNDLE_URL: &str = {:?};\npub const BUNDLE_INTEGRITY: &str = {:?};\n",
+        format!("{}/{}", url_prefix, file_name),
+        integrity
+    );
+    if let Some(modules_dir) = modules_dir {
+        let mut module_files = Vec::new();
+        list_files(modules_dir, &mut module_files);
+        module_files.sort();
+        let mut hasher = Sha384::new();
+        for file in &module_files {
+            hasher.update(file.strip_prefix(modules_dir).unwrap().to_string_lossy().as_bytes());
+            hasher.update(fs::read(file).unwrap());
+        }
+        let modules_dir_name = format!("leptos-material-modules-{}", short_hash(&hasher.finalize()));
+        for file in &module_files {
+            let destination = bundle_dir
+                .join(&modules_dir_name)
+                .join(file.strip_prefix(modules_dir).unwrap());
+            fs::create_dir_all(destination.parent().unwrap()).unwrap();
+            fs::copy(file, &destination)
+                .unwrap_or_else(|_| panic!("Unable to copy module to {:?}", destination));
+        }
+        constants.push_str(&format!(
+            "pub const MODULES_URL: &str = {:?};\n",
+            format!("{}/{}", url_prefix, modules_dir_name)
+        ));
+        let eager_module = format!("{}.js", EAGER_MODULE_NAME);
+        let eager_module_url = modules_dir.join(&eager_module).exists().then(|| {
+            format!("{}/{}/{}", url_prefix, modules_dir_name, eager_module)
+        });
+        constants.push_str(&format!(
+            "pub const EAGER_MODULE_URL: Option<&str> = {:?};\n",
+            eager_module_url
+        ));
+    }
+    let external_bundle_rs_path = format!(
+        "{}/{}",
+        env::var("OUT_DIR").unwrap(),
+        EXTERNAL_BUNDLE_RS_FILE_NAME
+    );
+    fs::write(&external_bundle_rs_path, constants)
+        .unwrap_or_else(|_| panic!("Unable to write {}", external_bundle_rs_path));
+}
+
+/// The `.codepoints` files of every font in `SYMBOL_FONTS`, downloaded once into the `node_modules` cache,
+/// or the file at `LEPTOS_MATERIAL_CODEPOINTS`.
+fn codepoints_files() -> Vec<PathBuf> {
+    if let Ok(path) = env::var(CODEPOINTS_ENV) {
+        println!("cargo::rerun-if-changed={}", path);
+        return vec![PathBuf::from(path)];
+    }
+    let cache_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("node_modules/.cache/leptos-material");
+    fs::create_dir_all(&cache_dir).unwrap();
+    SYMBOL_FONTS
+        .iter()
+        .map(|font| {
+            let path = cache_dir.join(format!("{}.codepoints", font));
+            if !path.exists() {
+                // The upstream file names are `MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].codepoints`.
+                let url = format!("{}/{}%5BFILL%2CGRAD%2Copsz%2Cwght%5D.codepoints", CODEPOINTS_URL, font);
+                // Downloaded next to the cache file first, so an interrupted download isn't used.
+                let download_path = path.with_extension("download");
+                let download = run_command(&format!(
+                    "curl -sSfL -o \"{}\" \"{}\"",
+                    download_path.display(),
+                    url
+                ))
+                .unwrap();
+                assert!(download.success(), "Unable to download {}", url);
+                fs::rename(&download_path, &path)
+                    .unwrap_or_else(|_| panic!("Unable to write {:?}", path));
+            }
+            path
+        })
+        .collect()
+}
+
+/// Generate the `Symbol` enum and `icon!` macro, included by `src/components/icon.rs`, from the codepoints files.
+/// Icons are drawn by codepoint in any of the fonts, so every file must give an icon the same codepoint.
+fn write_symbols() {
+    let mut codepoints_by_name = BTreeMap::<String, u32>::new();
+    for path in codepoints_files() {
+        let codepoints = fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("Unable to read {:?}", path));
+        for (name, codepoint) in codepoints.lines().filter_map(|line| line.split_once(' ')) {
+            let codepoint = u32::from_str_radix(codepoint.trim(), 16)
+                .unwrap_or_else(|_| panic!("Invalid codepoint for `{}` in {:?}", name, path));
+            let known = *codepoints_by_name.entry(name.to_string()).or_insert(codepoint);
+            assert!(
+                known == codepoint,
+                "`{}` has the codepoint {:x} in {:?}, but {:x} in another font",
+                name,
+                codepoint,
+                path,
+                known
+            );
+        }
+    }
+    let symbols: Vec<(&str, u32)> = codepoints_by_name
+        .iter()
+        .map(|(name, codepoint)| (name.as_str(), *codepoint))
+        .collect();
+    // `arrow_back` becomes `ArrowBack`. Names starting with a digit, like `10k`, are prefixed with `N`.
+    let variant = |name: &str| -> String {
+        let camel_case: String = name
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect();
+        if camel_case.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("N{}", camel_case)
+        } else {
+            camel_case
+        }
+    };
+    let mut variants = String::new();
+    let mut names = String::new();
+    let mut codepoint_arms = String::new();
+    let mut from_name_arms = String::new();
+    let mut macro_arms = String::new();
+    for (name, codepoint) in &symbols {
+        let variant = variant(name);
+        variants.push_str(&format!("    /// `{}`\n    {},\n", name, variant));
+        names.push_str(&format!("            Symbol::{} => {:?},\n", variant, name));
+        codepoint_arms.push_str(&format!("            Symbol::{} => '\\u{{{:x}}}',\n", variant, codepoint));
+        from_name_arms.push_str(&format!("            {:?} => Some(Symbol::{}),\n", name, variant));
+        macro_arms.push_str(&format!(
+            "    ({}) => {{ $crate::components::icon::Symbol::{} }};\n",
+            name, variant
+        ));
+    }
+    let source = format!(
+        r#"/// A Material Symbols icon, so a misspelled name is a compile error. Generated from the Material Symbols codepoints files.
+/// The [`icon!`](crate::icon) macro names one the way the font does, e.g. `icon!(chevron_left)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Symbol {{
+{variants}}}
+
+impl Symbol {{
+    /// The icon's ligature name, e.g. `chevron_left`.
+    pub const fn name(self) -> &'static str {{
+        match self {{
+{names}        }}
+    }}
+
+    /// The icon's codepoint in the Material Symbols fonts.
+    pub const fn codepoint(self) -> char {{
+        match self {{
+{codepoint_arms}        }}
+    }}
+
+    /// The icon with the ligature name `name`, if there is one.
+    pub fn from_name(name: &str) -> Option<Self> {{
+        match name {{
+{from_name_arms}            _ => None,
+        }}
+    }}
+}}
+
+/// A [`Symbol`](crate::components::icon::Symbol) named like in the font, e.g. `icon!(chevron_left)`.
+/// Names are checked at compile time.
+#[macro_export]
+macro_rules! icon {{
+{macro_arms}}}
+"#,
+    );
+    let symbols_rs_path = format!("{}/{}", env::var("OUT_DIR").unwrap(), SYMBOLS_RS_FILE_NAME);
+    fs::write(&symbols_rs_path, source)
+        .unwrap_or_else(|_| panic!("Unable to write {}", symbols_rs_path));
+}
+
+/// Panic if an enabled Rust module renders a material-web element that no enabled feature imports.
+/// Tags are found as `<md-...` in `view!` macros and as `"md-...` string literals, such as element names passed to
+/// `html::Custom::new`. Typography classes, such as `md-typescale-body-large`, are always imported and skipped.
+fn check_rendered_tags_are_imported(enabled_components: &[&Component]) {
+    let imported_tags: HashSet<&str> = enabled_components
+        .iter()
+        .flat_map(|component| component.tags.iter().copied())
+        .collect();
+    for component in enabled_components {
+        let Some(rust_module) = component.rust_module else {
+            continue;
+        };
+        for source_path in rust_module_sources(rust_module) {
+            println!("cargo::rerun-if-changed={}", source_path.display());
+            let source = fs::read_to_string(&source_path)
+                .unwrap_or_else(|_| panic!("Unable to read {:?}", source_path));
+            for prefix in ["<md-", "\"md-"] {
+                for (index, _) in source.match_indices(prefix) {
+                    let tag_start = index + prefix.len() - "md-".len();
+                    let tag: String = source[tag_start..]
+                        .chars()
+                        .take_while(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+                        .collect();
+                    if tag.starts_with("md-typescale-") {
+                        continue;
+                    }
+                    assert!(
+                        imported_tags.contains(tag.as_str()),
+                        "`{}` renders <{}>, which isn't imported by any enabled feature. Add the feature providing it to the `{}` feature in Cargo.toml.",
+                        source_path.display(),
+                        tag,
+                        component.feature
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The source file of `rust_module`, followed by its submodules' files, e.g. `src/components/chips/field.rs`.
+fn rust_module_sources(rust_module: &str) -> Vec<PathBuf> {
+    let mut sources = vec![PathBuf::from(format!("src/components/{}.rs", rust_module))];
+    let submodules_dir = PathBuf::from(format!("src/components/{}", rust_module));
+    if submodules_dir.is_dir() {
+        println!("cargo::rerun-if-changed={}", submodules_dir.display());
+        let mut submodules = Vec::new();
+        list_files(&submodules_dir, &mut submodules);
+        submodules.retain(|path| path.extension().is_some_and(|extension| extension == "rs"));
+        submodules.sort();
+        sources.extend(submodules);
+    }
+    sources
+}
+
+/// First 8 bytes of a digest as hex, used to version file names.
+fn short_hash(digest: &[u8]) -> String {
+    digest[..8].iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn list_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            list_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
 }
 
 pub fn run_command(command: &str) -> Result<std::process::ExitStatus, io::Error> {
@@ -111,7 +486,7 @@ fn is_feature_enabled(feature: &str) -> bool {
 }
 
 fn add_import<T: std::io::Write>(to_import: &str, file: &mut T) {
-    writeln!(file, "import '@material/web/{}.js';", to_import).unwrap_or_else(|_| panic!("Error adding import '{}' to imports.js",
+    writeln!(file, "import '@material/web/{}.js';", to_import).unwrap_or_else(|_| panic!("Error adding import '{}' to imports.js",
         to_import));
 }
 
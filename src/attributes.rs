@@ -0,0 +1,28 @@
+//! Typed props next to the `attr:` attributes passed to the same component.
+use leptos::{html::ElementDescriptor, Attribute, HtmlElement, IntoAttribute};
+
+pub(crate) trait TypedAttr: Sized {
+    /// Set the attribute `name` from a typed prop, unless `overrides`, the component's `attr:` attributes, also sets it.
+    /// Setting both would render the attribute twice on the server, where browsers keep the first one.
+    fn typed_attr(
+        self,
+        overrides: &[(&'static str, Attribute)],
+        name: &'static str,
+        value: impl IntoAttribute,
+    ) -> Self;
+}
+
+impl<El: ElementDescriptor + 'static> TypedAttr for HtmlElement<El> {
+    fn typed_attr(
+        self,
+        overrides: &[(&'static str, Attribute)],
+        name: &'static str,
+        value: impl IntoAttribute,
+    ) -> Self {
+        if overrides.iter().any(|(overridden, _)| *overridden == name) {
+            self
+        } else {
+            self.attr(name, value)
+        }
+    }
+}
@@ -1,4 +1,14 @@
-use leptos::{component, html, Attribute, Children, IntoAttribute, IntoView};
+use leptos::{
+    component, html, view, Action, Attribute, Children, IntoAttribute, IntoView, MaybeProp,
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet, View,
+};
+
+use super::progress::CircularProgress;
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::lazy::load_component_module;
+use crate::link::link;
+use crate::swap::swappable_element;
 
 #[derive(PartialEq, Clone, Default)]
 pub enum ButtonType {
@@ -31,7 +41,7 @@ impl IntoAttribute for ButtonType {
 
 /// Styles correspond to the 5 button types.
 /// This is used in the Button component to determine what element tag to use.
-#[derive(Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum ButtonStyle {
     #[default]
     Elevated,
@@ -55,16 +65,125 @@ impl ButtonStyle {
     }
 }
 
+/// Whether a [`Button`] or [`IconButton`](super::iconbutton::IconButton) is busy.
+/// Converts from a `bool`, a signal, or an [`Action`], which makes the button busy while the action is pending.
+#[derive(Clone, Copy, Default)]
+pub struct ButtonLoading(MaybeSignal<bool>);
+
+impl ButtonLoading {
+    pub fn get(&self) -> bool {
+        self.0.get()
+    }
+}
+
+impl From<bool> for ButtonLoading {
+    fn from(value: bool) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<ReadSignal<bool>> for ButtonLoading {
+    fn from(value: ReadSignal<bool>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<RwSignal<bool>> for ButtonLoading {
+    fn from(value: RwSignal<bool>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Memo<bool>> for ButtonLoading {
+    fn from(value: Memo<bool>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Signal<bool>> for ButtonLoading {
+    fn from(value: Signal<bool>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<I: 'static, O: 'static> From<Action<I, O>> for ButtonLoading {
+    fn from(action: Action<I, O>) -> Self {
+        Self(action.pending().into())
+    }
+}
+
+/// A spinner shown over a busy button's hidden label, so the button keeps its width.
+pub(crate) fn loading_indicator(loading: ButtonLoading) -> View {
+    (move || {
+        loading.get().then(|| {
+            view! {
+                <span class="leptos-material-button-progress" aria-hidden="true">
+                    <CircularProgress/>
+                </span>
+            }
+        })
+    })
+    .into_view()
+}
+
+element_ref! {
+    /// Handle for calling methods on a [`Button`] element, passed with the `element_ref` prop.
+    /// Methods do nothing while the button isn't mounted.
+    ButtonRef(web_sys::HtmlElement)
+}
+
 /// [MWC Docs](https://material-web.dev/components/button/)
 #[component]
 pub fn Button(
-    #[prop(optional)] style: ButtonStyle,
-    #[prop(optional)] button_type: ButtonType,
-    #[prop(attrs)] attr: Vec<(&'static str, Attribute)>,
+    /// Changing the style swaps the element, keeping the button's children.
+    #[prop(optional, into)]
+    style: MaybeSignal<ButtonStyle>,
+    #[prop(optional, into)] button_type: MaybeSignal<ButtonType>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Disable the button while keeping it focusable, e.g. so a tooltip can explain why it's disabled.
+    #[prop(optional, into)]
+    soft_disabled: MaybeSignal<bool>,
+    /// Disable the button and show a progress indicator in place of its label, e.g. while a server action runs.
+    /// Accepts an [`Action`] directly.
+    #[prop(optional, into)]
+    loading: ButtonLoading,
+    /// Render the button as a link to this URL. With the `router` feature, internal links navigate client-side.
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+    /// Where to open `href`, e.g. `_blank`.
+    #[prop(optional, into)]
+    target: MaybeProp<String>,
+    /// Place an `Icon` child with `element_slot="icon"` after the label instead of before it.
+    #[prop(optional, into)]
+    trailing_icon: MaybeSignal<bool>,
+    /// Name used when submitting the button's form.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    /// Value used when submitting the button's form.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
     children: Children,
+    #[prop(optional)] element_ref: ButtonRef,
 ) -> impl IntoView {
-    html::custom(html::Custom::new(style.get_element_name()))
-        .attrs(attr)
-        .attr("type", button_type.into_attribute())
-        .child(children())
+    load_component_module("button");
+    let children = children();
+    let loading_indicator = loading_indicator(loading);
+    swappable_element(style, ButtonStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "type", button_type.clone())
+            .typed_attr(&attr, "disabled", move || disabled.get() || loading.get())
+            .typed_attr(&attr, "soft-disabled", soft_disabled)
+            .typed_attr(&attr, "aria-busy", move || loading.get().then_some("true"))
+            .typed_attr(&attr, "trailing-icon", trailing_icon)
+            .typed_attr(&attr, "name", name.clone())
+            .typed_attr(&attr, "value", value.clone());
+        link(html, href.clone(), target.clone(), true, &attr)
+            .attrs(attr.clone())
+            .child(children.clone())
+            .child(loading_indicator.clone())
+    })
 }
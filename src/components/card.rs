@@ -1,8 +1,15 @@
-use leptos::{component, slot, view, Children, ChildrenFn, IntoView};
+use leptos::{
+    component, create_rw_signal, ev, html, slot, view, Callable, Callback, Children, ChildrenFn,
+    IntoView, MaybeProp, MaybeSignal, Signal, SignalGet, SignalGetUntracked, SignalSet,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{DragEvent, HtmlElement};
 
-use crate::components::elevation::Elevation;
+use crate::components::elevation::{Elevation, MAX_ELEVATION_LEVEL};
+use crate::components::icon::{Icon, Symbol};
+use crate::link::link;
 
-#[derive(Default, PartialEq)]
+#[derive(Clone, Copy, Default, PartialEq)]
 pub enum CardStyle {
     #[default]
     Elevated,
@@ -19,22 +26,201 @@ impl CardStyle {
         }
         .into()
     }
+
+    /// Elevation level of a card that isn't being interacted with.
+    pub fn get_resting_elevation(&self) -> u8 {
+        match self {
+            CardStyle::Elevated => 1,
+            CardStyle::Filled | CardStyle::Outlined => 0,
+        }
+    }
 }
 
 /// This is a native Leptos component.
 /// See [material.io](https://m3.material.io/components/cards/overview).
+///
+/// A card with `on_click` or `href` is interactive: the whole card is focusable and shows a ripple when pressed.
+/// Buttons in its `Actions` stay separately clickable.
 #[component]
 pub fn Card(
-    style: CardStyle,
+    #[prop(into)] style: MaybeSignal<CardStyle>,
+    /// Elevation level, from 0 to 5, while the card isn't being interacted with. Defaults to the style's level.
+    /// The card is raised while hovered or dragged, unless it's disabled.
+    #[prop(optional, into)]
+    level: MaybeProp<u8>,
+    /// Full width content shown above the rest of the card, such as an image.
+    #[prop(optional)]
+    media: Option<Media>,
     #[prop(optional)] headline: Option<Headline>,
-    children: Children,
+    #[prop(optional)] subhead: Option<Subhead>,
+    #[prop(optional)] supporting_text: Option<SupportingText>,
+    /// Buttons shown at the end of the card.
+    #[prop(optional)]
+    actions: Option<Actions>,
+    /// Called when the card is clicked, or activated with Enter or Space.
+    #[prop(optional, into)]
+    on_click: Option<Callback<()>>,
+    /// Navigate to this URL when the card is clicked, or activated with Enter. With the `router` feature, internal links navigate client-side.
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+    /// Where to open `href`, e.g. `_blank`.
+    #[prop(optional, into)]
+    target: MaybeProp<String>,
+    /// Dim the card and ignore clicks.
+    #[prop(optional, into)]
+    disabled: MaybeSignal<bool>,
+    /// Show a drag handle. Dragging the handle drags the card.
+    #[prop(optional, into)]
+    draggable: MaybeSignal<bool>,
+    #[prop(optional, into)] on_drag_start: Option<Callback<DragEvent>>,
+    #[prop(optional, into)] on_drag_end: Option<Callback<DragEvent>>,
+    #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let elevation = style.eq(&CardStyle::Elevated).then(Elevation);
-    let classes = format!("leptos-material-card {}", style.get_class_name());
+    let is_interactive = on_click.is_some() || href.get_untracked().is_some();
+    // Cards with an `href` get the link role instead.
+    let is_button = on_click.is_some() && href.get_untracked().is_none();
+    // Set while the drag handle is pressed, so the card can only be dragged by its handle.
+    let drag_armed = create_rw_signal(false);
+    let is_dragging = create_rw_signal(false);
+    let is_hovered = create_rw_signal(false);
+    let is_pressed = create_rw_signal(false);
+    // Following Material 3, cards rise one level on hover and three while dragged. Pressing an interactive card
+    // returns it to its resting level. Non-interactive elevated cards keep rising on hover, disabled cards never rise.
+    let elevation_level = move || {
+        let resting = level
+            .get()
+            .unwrap_or_else(|| style.get().get_resting_elevation());
+        let raise = if disabled.get() {
+            0
+        } else if is_dragging.get() {
+            3
+        } else if is_interactive && is_pressed.get() {
+            0
+        } else if is_hovered.get() && (is_interactive || style.get() == CardStyle::Elevated) {
+            1
+        } else {
+            0
+        };
+        resting.saturating_add(raise).min(MAX_ELEVATION_LEVEL)
+    };
+    let classes = move || {
+        format!(
+            "leptos-material-card {}{}{}{}",
+            style.get().get_class_name(),
+            if is_interactive { " interactive" } else { "" },
+            if disabled.get() { " disabled" } else { "" },
+            if is_dragging.get() { " dragged" } else { "" },
+        )
+    };
+    let activate = move || {
+        if let (Some(on_click), false) = (on_click, disabled.get_untracked()) {
+            on_click.call(());
+        }
+    };
+    let interaction_layers = is_interactive.then(|| {
+        view! {
+            <md-focus-ring class="leptos-material-card-focus-ring" inward></md-focus-ring>
+            <md-ripple disabled=move || disabled.get()></md-ripple>
+        }
+    });
+    let drag_handle = move || {
+        draggable.get().then(|| {
+            view! {
+                <span
+                    class="leptos-material-card-drag-handle"
+                    aria-hidden="true"
+                    on:pointerdown=move |_| drag_armed.set(true)
+                    on:pointerup=move |_| drag_armed.set(false)
+                >
+                    <Icon name=Symbol::DragIndicator/>
+                </span>
+            }
+        })
+    };
+    let media_view = media.map(|value| {
+        view! { <div class="leptos-material-card-media">{(value.children)()}</div> }
+    });
     let headline_view = headline.map(|value| {
         view! { <div class="leptos-material-card-headline">{(value.children)()}</div> }
     });
-    view! { <div class=classes>{elevation} {headline_view} {children()}</div> }
+    let subhead_view = subhead.map(|value| {
+        view! { <div class="leptos-material-card-subhead md-typescale-body-medium">{(value.children)()}</div> }
+    });
+    let supporting_text_view = supporting_text.map(|value| {
+        view! {
+            <div class="leptos-material-card-supporting-text md-typescale-body-medium">
+                {(value.children)()}
+            </div>
+        }
+    });
+    let actions_view = actions.map(|value| {
+        view! {
+            // Clicks on actions shouldn't also activate the card.
+            <div
+                class="leptos-material-card-actions"
+                on:click=|event| event.stop_propagation()
+                on:keydown=|event| event.stop_propagation()
+            >
+                {(value.children)()}
+            </div>
+        }
+    });
+    // Built without `view!`, since `link` adds attributes that server rendering only keeps on builder elements.
+    let card = html::div()
+        .attr("class", classes)
+        .attr("role", is_button.then_some("button"))
+        .attr("tabindex", move || (is_interactive && !disabled.get()).then_some("0"))
+        .attr("aria-disabled", move || disabled.get().then_some("true"))
+        .attr("draggable", move || (draggable.get() && drag_armed.get()).to_string())
+        .on(ev::click, move |_| activate())
+        .on(ev::pointerenter, move |_| is_hovered.set(true))
+        .on(ev::pointerleave, move |_| {
+            is_hovered.set(false);
+            is_pressed.set(false);
+        })
+        .on(ev::pointerdown, move |_| is_pressed.set(true))
+        .on(ev::pointerup, move |_| is_pressed.set(false))
+        .on(ev::keydown, move |event| {
+            let key = event.key();
+            if key == "Enter" && is_interactive {
+                // Clicking runs `on_click` and follows `href` the same way a pointer click does.
+                event.prevent_default();
+                if let Some(card) = event
+                    .current_target()
+                    .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                {
+                    card.click();
+                }
+            } else if key == " " && on_click.is_some() {
+                event.prevent_default();
+                activate();
+            }
+        })
+        .on(ev::dragstart, move |event| {
+            is_dragging.set(true);
+            if let Some(on_drag_start) = on_drag_start {
+                on_drag_start.call(event);
+            }
+        })
+        .on(ev::dragend, move |event| {
+            is_dragging.set(false);
+            drag_armed.set(false);
+            if let Some(on_drag_end) = on_drag_end {
+                on_drag_end.call(event);
+            }
+        })
+        .child(view! { <Elevation level=Signal::derive(elevation_level)/> })
+        .child(interaction_layers)
+        .child(media_view)
+        .child(drag_handle)
+        .child(headline_view)
+        .child(subhead_view)
+        .child(supporting_text_view)
+        .child(children.map(|children| children()))
+        .child(actions_view);
+    // A disabled card doesn't navigate.
+    let href = MaybeProp::derive(move || href.get().filter(|_| !disabled.get()));
+    link(card, href, target, false, &[])
 }
 
 /// From [material.io](https://m3.material.io/components/cards/guidelines#001f3b36-abe2-42dd-90cd-958a465377fb): Headline text often communicates the subject of the card, such as the name of a photo album or article.
@@ -42,3 +228,27 @@ pub fn Card(
 pub struct Headline {
     children: ChildrenFn,
 }
+
+/// Full width media, such as a photo, shown at the top of a [`Card`].
+#[slot]
+pub struct Media {
+    children: ChildrenFn,
+}
+
+/// Secondary text shown below a [`Card`]'s headline, such as an author or location.
+#[slot]
+pub struct Subhead {
+    children: ChildrenFn,
+}
+
+/// Body text of a [`Card`], such as a summary of an article.
+#[slot]
+pub struct SupportingText {
+    children: ChildrenFn,
+}
+
+/// Buttons shown at the end of a [`Card`].
+#[slot]
+pub struct Actions {
+    children: ChildrenFn,
+}
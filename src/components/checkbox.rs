@@ -1,30 +1,277 @@
+use std::{collections::HashSet, hash::Hash};
+
 use leptos::{
-    component, view, IntoView, Signal, SignalGet,
-    SignalSet, SignalSetter,
+    component, ev, view, Callable, Callback, Children, For, IntoView, MaybeProp, MaybeSignal,
+    RwSignal, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalSetter, SignalUpdate,
+    SignalWith,
 };
 
+use leptos::html;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use web_sys::js_sys::Reflect;
+
+use crate::element_ref::element_ref;
+use crate::events::{on_event, target_flag};
+use crate::lazy::load_component_module;
+
+/// The state of a tri-state [`Checkbox`], such as a parent checkbox whose children are only partly checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Shown as a dash. Toggling an indeterminate checkbox checks it.
+    Indeterminate,
+}
+
+impl From<bool> for CheckboxState {
+    fn from(value: bool) -> Self {
+        if value {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Unchecked
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = web_sys::HtmlElement)]
+    type MdCheckbox;
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &MdCheckbox, error: &str);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &MdCheckbox) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &MdCheckbox) -> bool;
+}
+
+element_ref! {
+    /// Handle for calling methods on a [`Checkbox`] element, passed with the `element_ref` prop.
+    /// Methods do nothing, and return `None`, while the checkbox isn't mounted.
+    CheckboxRef(MdCheckbox)
+}
+
+impl CheckboxRef {
+    /// Mark the checkbox invalid with `error` as its message. An empty string marks it valid.
+    pub fn set_custom_validity(&self, error: &str) {
+        if let Some(element) = self.element() {
+            element.set_custom_validity(error);
+        }
+    }
+
+    /// Whether the checkbox is valid, e.g. checked when required, without showing an error.
+    pub fn check_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.check_validity())
+    }
+
+    /// Whether the checkbox is valid, showing its error message if it isn't.
+    pub fn report_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.report_validity())
+    }
+}
+
 /// [MWC Docs](https://material-web.dev/components/checkbox/)
 #[component]
 pub fn Checkbox(
     #[prop(optional, into)] get_checked: Option<Signal<bool>>,
-    #[prop(optional, into)] set_checked: Option<SignalSetter<bool>>,
+    /// Called with the new checked state when the user toggles the checkbox, by clicking or with the keyboard.
+    /// Without a setter, a checkbox with `get_checked` can't be toggled by the user.
+    #[prop(optional, into)]
+    set_checked: Option<SignalSetter<bool>>,
+    /// Bound to a tri-state checkbox's state. Takes precedence over `get_checked` and `set_checked`.
+    #[prop(optional)]
+    state: Option<RwSignal<CheckboxState>>,
+    /// Show the checkbox as indeterminate, e.g. when only some of a group's options are checked.
+    #[prop(optional, into)]
+    indeterminate: MaybeSignal<bool>,
+    /// Text shown next to the checkbox. Clicking it toggles the checkbox.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// Content shown after `label`, also toggling the checkbox when clicked.
+    #[prop(optional)]
+    children: Option<Children>,
+    /// Id of the checkbox element, so other `<label for>` elements can refer to it.
+    #[prop(optional, into)]
+    id: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    #[prop(optional, into)] required: MaybeSignal<bool>,
+    /// Name used when submitting the checkbox with a form.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    /// Value submitted with the form when checked. Defaults to `on`.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    /// Called with the new checked state when the user toggles the checkbox.
+    #[prop(optional, into)]
+    on_change: Option<Callback<bool>>,
+    #[prop(optional)] element_ref: CheckboxRef,
 ) -> impl IntoView {
-    view! {
-        <md-checkbox
-            prop:checked=move || match get_checked {
-                Some(value) => value.get(),
-                None => false,
+    load_component_module("checkbox");
+    // Mirrored as attributes so server-rendered HTML matches the hydrated state.
+    let is_checked = move || match (state, get_checked) {
+        (Some(state), _) => state.get() == CheckboxState::Checked,
+        (None, Some(checked)) => checked.get(),
+        (None, None) => false,
+    };
+    let is_indeterminate = move || {
+        indeterminate.get() || state.is_some_and(|state| state.get() == CheckboxState::Indeterminate)
+    };
+    let has_label = label.get_untracked().is_some() || children.is_some();
+    let checkbox = html::custom(html::Custom::new("md-checkbox"))
+        .node_ref(element_ref.0)
+        .attr("id", id.clone())
+        .attr("disabled", disabled)
+        .attr("required", required)
+        .attr("name", name)
+        .attr("value", value)
+        .attr("checked", is_checked)
+        .prop("checked", is_checked)
+        .attr("indeterminate", is_indeterminate)
+        .prop("indeterminate", is_indeterminate)
+        // The change event also fires when toggling with the keyboard.
+        .on(ev::Custom::<web_sys::Event>::new("change"), move |event| {
+            let checked = target_flag(&event, "checked");
+            match (state, set_checked) {
+                (Some(state), _) => state.set(checked.into()),
+                (None, Some(setter)) => setter.set(checked),
+                // Without a setter the checked state can't change, so undo the element's toggle.
+                (None, None) => {
+                    if let (Some(target), Some(checked)) = (event.target(), get_checked) {
+                        let _ = Reflect::set(
+                            &target,
+                            &JsValue::from_str("checked"),
+                            &JsValue::from_bool(checked.get_untracked()),
+                        );
+                    }
+                }
             }
+        });
+    let checkbox = on_event(checkbox, "change", on_change, |event| target_flag(event, "checked"));
+    if has_label {
+        // Wrapping the checkbox in its label associates them without needing a unique id.
+        view! {
+            <label class="leptos-material-checkbox-label" for=id>
+                {checkbox}
+                {label}
+                {children.map(|children| children())}
+            </label>
+        }
+        .into_view()
+    } else {
+        checkbox.into_view()
+    }
+}
 
-            // On mouse down is more responsive than onclick.
-            on:mousedown=move |_| {
-                if let Some(getter) = get_checked {
-                    if let Some(setter) = set_checked {
-                        setter.set(!getter.get());
-                    }
+/// A list of checkboxes, one per option, bound to the set of checked options.
+/// With `select_all_label`, a parent checkbox above the list is checked when every option is,
+/// indeterminate when only some are, and checks or unchecks every option when toggled.
+/// ```ignore
+/// let toppings = create_rw_signal(HashSet::from(["Cheese"]));
+/// view! {
+///     <CheckboxGroup
+///         selected=toppings
+///         options=vec!["Cheese", "Olives", "Peppers"]
+///         option_label=|topping: &str| topping.to_string()
+///         select_all_label="All toppings"
+///     />
+/// }
+/// ```
+#[component]
+pub fn CheckboxGroup<T>(
+    /// Bound to the checked options.
+    selected: RwSignal<HashSet<T>>,
+    /// The options, in the order they're shown.
+    #[prop(into)]
+    options: MaybeSignal<Vec<T>>,
+    /// Text shown next to each option's checkbox.
+    #[prop(into)]
+    option_label: Callback<T, String>,
+    /// Label of a parent checkbox that checks or unchecks every option. No parent checkbox is shown without it.
+    #[prop(optional, into)]
+    select_all_label: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    let options = Signal::derive(move || options.get());
+    let select_all_label = Signal::derive(move || select_all_label.get());
+    // Options that are selected but no longer listed don't affect the parent checkbox.
+    let checked_count = move || {
+        selected.with(|selected| {
+            options.with(|options| options.iter().filter(|option| selected.contains(option)).count())
+        })
+    };
+    let all_checked = Signal::derive(move || {
+        let count = checked_count();
+        count > 0 && count == options.with(Vec::len)
+    });
+    let some_checked = Signal::derive(move || checked_count() > 0 && !all_checked.get());
+    let set_all_checked = SignalSetter::map(move |checked: bool| {
+        let options = options.get_untracked();
+        selected.update(|selected| {
+            for option in options {
+                if checked {
+                    selected.insert(option);
+                } else {
+                    selected.remove(&option);
                 }
             }
-        >
-        </md-checkbox>
+        })
+    });
+    let select_all = move || {
+        select_all_label.get().map(|label| {
+            view! {
+                <Checkbox
+                    get_checked=all_checked
+                    set_checked=set_all_checked
+                    indeterminate=some_checked
+                    label=label
+                    disabled=disabled
+                />
+            }
+        })
+    };
+    let has_parent = move || select_all_label.get().is_some();
+    view! {
+        <div class="leptos-material-checkbox-group" role="group" aria-label=select_all_label>
+            {select_all}
+            <div class="leptos-material-checkbox-group-options" class:nested=has_parent>
+                <For
+                    each=move || options.get()
+                    key=|option| option.clone()
+                    children=move |option| {
+                        let contains_option = option.clone();
+                        let toggled_option = option.clone();
+                        let get_checked = Signal::derive(move || {
+                            selected.with(|selected| selected.contains(&contains_option))
+                        });
+                        let set_checked = SignalSetter::map(move |checked: bool| {
+                            selected
+                                .update(|selected| {
+                                    if checked {
+                                        selected.insert(toggled_option.clone());
+                                    } else {
+                                        selected.remove(&toggled_option);
+                                    }
+                                })
+                        });
+                        view! {
+                            <Checkbox
+                                get_checked=get_checked
+                                set_checked=set_checked
+                                label=option_label.call(option)
+                                disabled=disabled
+                            />
+                        }
+                    }
+                />
+
+            </div>
+        </div>
     }
 }
@@ -1,6 +1,25 @@
-use leptos::{component, html, Attribute, Children, IntoView};
+use std::{collections::HashSet, hash::Hash};
 
-#[derive(Default)]
+use leptos::{
+    component, ev, html, view, Attribute, Callable, Callback, Children, For, IntoView, MaybeProp,
+    MaybeSignal, RwSignal, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate,
+    SignalWith,
+};
+
+use super::icon::{Icon, IconSource};
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::events::{on_event, target_flag};
+use crate::lazy::load_component_module;
+use crate::link::link;
+use crate::swap::swappable_element;
+
+#[cfg(feature = "textfield")]
+mod field;
+#[cfg(feature = "textfield")]
+pub use field::ChipsField;
+
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum ChipType {
     #[default]
     Assist,
@@ -24,17 +43,245 @@ impl ChipType {
 /// [MWC Docs](https://material-web.dev/components/chip/#chip-sets)
 #[component]
 pub fn Chipset(children: Children) -> impl IntoView {
+    load_component_module("chips");
     leptos::view! { <md-chip-set>{children()}</md-chip-set> }
 }
 
+element_ref! {
+    /// Handle for calling methods on a [`Chip`] element, passed with the `element_ref` prop.
+    /// Methods do nothing while the chip isn't mounted.
+    ChipRef(web_sys::HtmlElement)
+}
+
 /// [MWC Docs](https://material-web.dev/components/chip/)
 #[component]
 pub fn Chip(
-    #[prop(optional)] chip_type: ChipType,
-    #[prop(attrs)] attr: Vec<(&'static str, Attribute)>,
-    children: Children,
+    /// Changing the type swaps the element, keeping the chip's children.
+    #[prop(optional, into)]
+    chip_type: MaybeSignal<ChipType>,
+    #[prop(optional, into)] label: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Disable the chip while keeping it focusable, e.g. so a tooltip can explain why it's disabled.
+    #[prop(optional, into)]
+    soft_disabled: MaybeSignal<bool>,
+    /// Assist, filter and suggestion chips only. Use the elevated style instead of an outline.
+    #[prop(optional, into)]
+    elevated: MaybeSignal<bool>,
+    /// Filter and input chips only.
+    #[prop(optional, into)]
+    selected: MaybeSignal<bool>,
+    /// Filter chips only. Show a remove button.
+    #[prop(optional, into)]
+    removable: MaybeSignal<bool>,
+    /// Icon shown before the label.
+    #[prop(optional, into)]
+    icon: Option<IconSource>,
+    /// Input chips only. URL of a round avatar image shown before the label, e.g. a contact's photo.
+    #[prop(optional, into)]
+    avatar: MaybeProp<String>,
+    /// Input chips only. The chip has no primary action, only the remove button.
+    #[prop(optional, into)]
+    remove_only: MaybeSignal<bool>,
+    /// Assist, input and suggestion chips only. Render the chip as a link to this URL.
+    /// With the `router` feature, internal links navigate client-side.
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+    /// Where to open `href`, e.g. `_blank`.
+    #[prop(optional, into)]
+    target: MaybeProp<String>,
+    /// Keep the chip focusable while disabled.
+    #[prop(optional, into)]
+    always_focusable: MaybeSignal<bool>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
+    #[prop(optional)] children: Option<Children>,
+    /// Filter chips only. Called with the chip's new selected state when the user toggles it.
+    #[prop(optional, into)]
+    on_selected_change: Option<Callback<bool>>,
+    /// Called when the user clicks the remove button of an input or removable filter chip.
+    /// The chip then stays in place, so it should be removed from the data it's rendered from.
+    #[prop(optional, into)]
+    on_remove: Option<Callback<()>>,
+    #[prop(optional)] element_ref: ChipRef,
 ) -> impl IntoView {
-    html::custom(html::Custom::new(chip_type.get_element_name()))
-        .attrs(attr)
-        .child(children())
+    load_component_module("chips");
+    let avatar_src = avatar.clone();
+    let has_avatar = Signal::derive(move || avatar_src.get().is_some());
+    let leading = view! {
+        {move || {
+            avatar
+                .get()
+                .map(|src| view! { <img slot="icon" src=src alt=""/> })
+        }}
+        {icon.map(|icon| view! { <Icon name=icon element_slot="icon"/> })}
+    };
+    let children = children.map(|children| children());
+    swappable_element(chip_type, ChipType::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "label", label.clone())
+            .typed_attr(&attr, "disabled", disabled)
+            .typed_attr(&attr, "soft-disabled", soft_disabled)
+            .typed_attr(&attr, "elevated", elevated)
+            .typed_attr(&attr, "selected", selected)
+            .prop("selected", selected)
+            .typed_attr(&attr, "removable", removable)
+            .typed_attr(&attr, "avatar", has_avatar)
+            .typed_attr(&attr, "remove-only", remove_only)
+            .typed_attr(&attr, "always-focusable", always_focusable);
+        let html = link(html, href.clone(), target.clone(), true, &attr)
+            .attrs(attr.clone())
+            .child(leading.clone())
+            .child(children.clone());
+        let html = on_event(html, "change", on_selected_change, |event| {
+            target_flag(event, "selected")
+        });
+        match on_remove {
+            // Otherwise the element removes itself, leaving the view out of sync with the DOM.
+            Some(on_remove) => html.on(ev::Custom::<web_sys::Event>::new("remove"), move |event| {
+                event.prevent_default();
+                on_remove.call(())
+            }),
+            None => html,
+        }
+    })
+}
+
+/// A set of filter chips, one per option, bound to the set of selected options.
+/// ```ignore
+/// let sizes = create_rw_signal(HashSet::new());
+/// view! { <FilterChipSet selected=sizes options=vec!["S", "M", "L"] option_label=|size: &str| size.to_string()/> }
+/// ```
+#[component]
+pub fn FilterChipSet<T>(
+    /// Bound to the selected options.
+    selected: RwSignal<HashSet<T>>,
+    /// The options, in the order they're shown.
+    #[prop(into)]
+    options: MaybeSignal<Vec<T>>,
+    /// Text shown on each option's chip.
+    #[prop(into)]
+    option_label: Callback<T, String>,
+    /// Icon shown on an option's chip.
+    #[prop(optional, into)]
+    option_icon: Option<Callback<T, Option<IconSource>>>,
+    /// Allow only one option to be selected. Selecting an option deselects the others.
+    #[prop(optional, into)]
+    single_select: MaybeSignal<bool>,
+    /// Use the elevated chip style instead of an outline.
+    #[prop(optional, into)]
+    elevated: MaybeSignal<bool>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    let options = Signal::derive(move || options.get());
+    view! {
+        <Chipset>
+            <For
+                each=move || options.get()
+                key=|option| option.clone()
+                children=move |option| {
+                    let contains_option = option.clone();
+                    let toggled_option = option.clone();
+                    let is_selected = Signal::derive(move || {
+                        selected.with(|selected| selected.contains(&contains_option))
+                    });
+                    let on_selected_change = move |is_now_selected: bool| {
+                        if is_now_selected && single_select.get_untracked() {
+                            selected.set(HashSet::from([toggled_option.clone()]));
+                        } else if is_now_selected {
+                            selected.update(|selected| {
+                                selected.insert(toggled_option.clone());
+                            });
+                        } else {
+                            selected.update(|selected| {
+                                selected.remove(&toggled_option);
+                            });
+                        }
+                    };
+                    view! {
+                        <Chip
+                            chip_type=ChipType::Filter
+                            label=option_label.call(option.clone())
+                            selected=is_selected
+                            elevated=elevated
+                            disabled=disabled
+                            on_selected_change=on_selected_change
+                        >
+                            {option_icon
+                                .and_then(|option_icon| option_icon.call(option))
+                                .map(|icon| view! { <Icon name=icon element_slot="icon"/> })}
+                        </Chip>
+                    }
+                }
+            />
+
+        </Chipset>
+    }
+}
+
+/// A set of input chips, one per item, which removes an item from `items` when its chip's remove button is clicked.
+/// Items are told apart by value, so `items` shouldn't contain duplicates.
+#[component]
+pub fn InputChipSet<T>(
+    /// Bound to the items. Removed chips are removed from here.
+    items: RwSignal<Vec<T>>,
+    /// Text shown on each item's chip.
+    #[prop(into)]
+    item_label: Callback<T, String>,
+    /// Icon shown on an item's chip.
+    #[prop(optional, into)]
+    item_icon: Option<Callback<T, Option<IconSource>>>,
+    /// URL of an avatar image shown on an item's chip. Takes the place of `item_icon`.
+    #[prop(optional, into)]
+    item_avatar: Option<Callback<T, Option<String>>>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Called with each item the user removes.
+    #[prop(optional, into)]
+    on_remove: Option<Callback<T>>,
+) -> impl IntoView
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    view! {
+        <Chipset>
+            <For
+                each=move || items.get()
+                key=|item| item.clone()
+                children=move |item| {
+                    let removed_item = item.clone();
+                    let remove = move |_| {
+                        items.update(|items| {
+                            if let Some(index) = items.iter().position(|item| *item == removed_item) {
+                                items.remove(index);
+                            }
+                        });
+                        if let Some(on_remove) = on_remove {
+                            on_remove.call(removed_item.clone());
+                        }
+                    };
+                    let avatar = item_avatar.and_then(|item_avatar| item_avatar.call(item.clone()));
+                    let icon = match avatar {
+                        Some(_) => None,
+                        None => item_icon.and_then(|item_icon| item_icon.call(item.clone())),
+                    };
+                    view! {
+                        <Chip
+                            chip_type=ChipType::Input
+                            label=item_label.call(item)
+                            avatar=avatar
+                            disabled=disabled
+                            on_remove=remove
+                        >
+                            {icon.map(|icon| view! { <Icon name=icon element_slot="icon"/> })}
+                        </Chip>
+                    }
+                }
+            />
+
+        </Chipset>
+    }
 }
@@ -0,0 +1,243 @@
+use leptos::{
+    component, create_effect, create_node_ref, create_rw_signal, html::Div, view, Callable,
+    Callback, CollectView, IntoView, MaybeProp, MaybeSignal, RwSignal, Signal, SignalGet,
+    SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use web_sys::{KeyboardEvent, MouseEvent};
+
+use super::InputChipSet;
+use crate::components::textfield::{TextField, TextFieldRef, TextFieldStyle};
+
+/// A text field that turns what's typed into input chips, e.g. for tags or email addresses.
+/// An entry is added when Enter or a comma is typed, and pasting comma separated text adds each entry.
+/// Backspace in the empty field removes the last entry.
+/// The arrow keys move focus from the field onto the suggestions, where Enter adds the focused one.
+/// ```ignore
+/// let recipients = create_rw_signal(Vec::new());
+/// let validate = |entry: String| {
+///     if entry.contains('@') { Ok(entry.to_lowercase()) } else { Err(format!("{entry} isn't an email address")) }
+/// };
+/// view! { <ChipsField values=recipients label="To" validate=validate/> }
+/// ```
+#[component]
+pub fn ChipsField(
+    /// Bound to the entries. Entries are added only once.
+    values: RwSignal<Vec<String>>,
+    #[prop(optional, into)] style: MaybeSignal<TextFieldStyle>,
+    #[prop(optional, into)] label: MaybeProp<String>,
+    #[prop(optional, into)] placeholder: MaybeProp<String>,
+    /// Checks each entry before it's added, returning the entry to add, e.g. trimmed or lowercased,
+    /// or an error message. Invalid entries are left in the field with the error shown below it.
+    #[prop(optional, into)]
+    validate: Option<Callback<String, Result<String, String>>>,
+    /// Suggested entries for the text typed so far, shown in a list below the field.
+    #[prop(optional, into)]
+    suggestions: Option<Callback<String, Vec<String>>>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+) -> impl IntoView {
+    let field_ref = TextFieldRef::new();
+    let text = create_rw_signal(String::new());
+    let error = create_rw_signal(None::<String>);
+    // The suggestion with keyboard focus.
+    let highlighted = create_rw_signal(None::<usize>);
+    let suggestions_dismissed = create_rw_signal(false);
+    let pasted = create_rw_signal(false);
+
+    // Add an entry, returning it unchanged if it's invalid.
+    let add = move |entry: &str| -> Option<String> {
+        let entry = entry.trim().to_string();
+        if entry.is_empty() {
+            return None;
+        }
+        let entry = match validate.map(|validate| validate.call(entry.clone())) {
+            Some(Ok(entry)) => entry,
+            Some(Err(message)) => {
+                error.set(Some(message));
+                return Some(entry);
+            }
+            None => entry,
+        };
+        values.update(|values| {
+            if !values.contains(&entry) {
+                values.push(entry);
+            }
+        });
+        None
+    };
+    // Add every comma separated entry, keeping invalid ones and any unfinished last entry in the field.
+    let add_entries = move |input: &str, keep_last: bool| {
+        error.set(None);
+        let mut entries: Vec<&str> = input.split(',').collect();
+        let last = if keep_last { entries.pop() } else { None };
+        let mut remaining: Vec<String> = entries.into_iter().filter_map(add).collect();
+        remaining.extend(last.map(str::to_string));
+        text.set(remaining.join(", "));
+        highlighted.set(None);
+    };
+
+    let suggested = Signal::derive(move || {
+        let Some(suggestions) = suggestions else {
+            return Vec::new();
+        };
+        let text = text.get();
+        if text.trim().is_empty() || suggestions_dismissed.get() {
+            return Vec::new();
+        }
+        let entered = values.get();
+        suggestions
+            .call(text)
+            .into_iter()
+            .filter(|suggestion| !entered.contains(suggestion))
+            .collect()
+    });
+
+    let on_input = move |input: String| {
+        suggestions_dismissed.set(false);
+        highlighted.set(None);
+        // Pasted text is complete, so its last entry is added too.
+        let pasting = pasted.get_untracked();
+        pasted.set(false);
+        if input.contains(',') {
+            add_entries(&input, !pasting);
+        }
+    };
+    // Add a suggestion and return focus to the text field, since the suggestion list closes.
+    let choose = move |suggestion: &str| {
+        add_entries(suggestion, false);
+        field_ref.focus();
+    };
+    let on_keydown = move |event: KeyboardEvent| {
+        let suggestion_count = suggested.with_untracked(Vec::len);
+        match event.key().as_str() {
+            "Enter" => {
+                event.prevent_default();
+                add_entries(&text.get_untracked(), false);
+            }
+            "Backspace" if text.with_untracked(String::is_empty) => {
+                values.update(|values| {
+                    values.pop();
+                });
+            }
+            "ArrowDown" if suggestion_count > 0 => {
+                event.prevent_default();
+                highlighted.set(Some(0));
+            }
+            "ArrowUp" if suggestion_count > 0 => {
+                event.prevent_default();
+                highlighted.set(Some(suggestion_count - 1));
+            }
+            "Escape" => suggestions_dismissed.set(true),
+            _ => {}
+        }
+    };
+    let on_suggestion_keydown = move |event: KeyboardEvent, index: usize, suggestion: &str| {
+        // Keys on a suggestion aren't typed in the text field.
+        event.stop_propagation();
+        let suggestion_count = suggested.with_untracked(Vec::len);
+        match event.key().as_str() {
+            "Enter" | " " => {
+                event.prevent_default();
+                choose(suggestion);
+            }
+            "ArrowDown" => {
+                event.prevent_default();
+                highlighted.set(Some((index + 1) % suggestion_count));
+            }
+            "ArrowUp" => {
+                event.prevent_default();
+                highlighted.set(Some((index + suggestion_count - 1) % suggestion_count));
+            }
+            "Escape" => {
+                suggestions_dismissed.set(true);
+                highlighted.set(None);
+                field_ref.focus();
+            }
+            // Typing goes back to the text field.
+            key if key.chars().count() == 1 || key == "Backspace" => {
+                highlighted.set(None);
+                field_ref.focus();
+            }
+            _ => {}
+        }
+    };
+
+    let suggestion_list = move || {
+        let suggested = suggested.get();
+        (!suggested.is_empty()).then(|| {
+            let options = suggested
+                .into_iter()
+                .enumerate()
+                .map(|(index, suggestion)| {
+                    let option_ref = create_node_ref::<Div>();
+                    // Follow the highlighted suggestion with DOM focus.
+                    create_effect(move |_| {
+                        if highlighted.get() == Some(index) {
+                            if let Some(option) = option_ref.get() {
+                                let _ = option.focus();
+                            }
+                        }
+                    });
+                    let clicked = suggestion.clone();
+                    let typed = suggestion.clone();
+                    view! {
+                        <div
+                            class="leptos-material-chips-field-suggestion md-typescale-body-large"
+                            class:highlighted=move || highlighted.get() == Some(index)
+                            role="option"
+                            tabindex="-1"
+                            aria-selected=move || (highlighted.get() == Some(index)).to_string()
+                            node_ref=option_ref
+                            on:keydown=move |event: KeyboardEvent| on_suggestion_keydown(event, index, &typed)
+                            // Focus moved elsewhere, not to another suggestion.
+                            on:blur=move |_| {
+                                if highlighted.get_untracked() == Some(index) {
+                                    highlighted.set(None);
+                                }
+                            }
+                            // Mousedown keeps focus where it is.
+                            on:mousedown=move |event: MouseEvent| {
+                                event.prevent_default();
+                                choose(&clicked);
+                            }
+                        >
+                            {suggestion}
+                        </div>
+                    }
+                })
+                .collect_view();
+            view! {
+                <div class="leptos-material-chips-field-suggestions" role="listbox">
+                    {options}
+                </div>
+            }
+        })
+    };
+    // The suggestions take keyboard focus themselves, rather than the text field pointing at the highlighted one with
+    // `aria-activedescendant`, because the field's input is in its shadow root, which ids in the page can't be referenced from.
+
+    view! {
+        <div class="leptos-material-chips-field">
+            <InputChipSet items=values item_label=|value: String| value disabled=disabled/>
+            // Only keys typed in the text field, since chips handle their own keys, such as Backspace to remove them.
+            // Suggestions handle theirs too.
+            <div
+                class="leptos-material-chips-field-input"
+                on:keydown=on_keydown
+                on:paste=move |_| pasted.set(true)
+            >
+                <TextField
+                    style=style
+                    value=text
+                    label=label
+                    placeholder=placeholder
+                    error=Signal::derive(move || error.with(Option::is_some))
+                    error_text=error
+                    disabled=disabled
+                    on_input=on_input
+                    element_ref=field_ref
+                />
+                {suggestion_list}
+            </div>
+        </div>
+    }
+}
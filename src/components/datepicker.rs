@@ -1,22 +1,33 @@
 
 use crate::components::button::ButtonStyle;
-use crate::components::icon::Icon;
+use crate::components::icon::{Icon, Symbol};
 use crate::components::{button::Button, button::ButtonType, iconbutton::IconButton};
 use chrono::Datelike;
+use chrono::Days;
 use chrono::Local;
+use chrono::Months;
 use chrono::NaiveDate;
-use leptos::html::Button;
+use leptos::html::{AnyElement, Button, Dialog, Div, ElementDescriptor};
+use leptos::{document, on_cleanup, window, Children, HtmlElement, NodeRef};
+use std::cell::RefCell;
+use std::rc::Rc;
 use leptos::DynAttrs;
 use leptos::RwSignal;
+use leptos::MaybeSignal;
 use leptos::Signal;
 use leptos::SignalGet;
 use leptos::SignalGetUntracked;
 use leptos::SignalSet;
+use leptos::SignalWith;
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+use leptos::{batch, store_value, Callable, Callback, View, ViewFn};
 use leptos::{component, view, CollectView, IntoView};
 use leptos::{
     create_effect, create_node_ref, create_rw_signal, Show, WriteSignal,
 };
-use web_sys::{MouseEvent, ScrollIntoViewOptions, ScrollLogicalPosition};
+use std::cmp::Ordering;
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent, Node, PointerEvent};
+use web_sys::{ScrollIntoViewOptions, ScrollLogicalPosition};
 // use time::{Date};
 
 use crate::components::elevation::Elevation;
@@ -73,7 +84,7 @@ fn DatePickerHeaderButton(
             attr:disabled=arrows_disabled
             on:click=decrement_action.clone()
         >
-            {move || arrows_visible().then(|| view! { <Icon name="chevron_left"/> })}
+            {move || arrows_visible().then(|| view! { <Icon name=Symbol::ChevronLeft/> })}
         </IconButton>
         <Button
             button_type=ButtonType::Button
@@ -92,9 +103,9 @@ fn DatePickerHeaderButton(
 
             {move || {
                 let icon = if state_to_set == state.get() {
-                    "arrow_drop_up"
+                    Symbol::ArrowDropUp
                 } else {
-                    "arrow_drop_down"
+                    Symbol::ArrowDropDown
                 };
                 let show_dropdown_arrow = state.get() == DatePickerState::SelectDay
                     || state.get() == state_to_set;
@@ -107,7 +118,7 @@ fn DatePickerHeaderButton(
             attr:disabled=arrows_disabled
             on:click=increment_action.clone()
         >
-            {move || arrows_visible().then(|| view! { <Icon name="chevron_right"/> })}
+            {move || arrows_visible().then(|| view! { <Icon name=Symbol::ChevronRight/> })}
         </IconButton>
     }
 }
@@ -115,15 +126,15 @@ fn DatePickerHeaderButton(
 #[component]
 fn DatePickerMenuButton(#[prop(into)] value: String, selected: bool) -> impl IntoView {
     let button_node_ref = create_node_ref::<Button>();
-    create_effect(move |v| {
-        if selected {
-            button_node_ref.get()
-                .unwrap()
-                .scroll_into_view_with_scroll_into_view_options(
-                    ScrollIntoViewOptions::new()
-                        .block(ScrollLogicalPosition::Center)
-                        .inline(ScrollLogicalPosition::Start),
-                );
+    create_effect(move |_| {
+        if !selected {
+            return;
+        }
+        if let Some(button) = button_node_ref.get() {
+            let options = ScrollIntoViewOptions::new();
+            options.set_block(ScrollLogicalPosition::Center);
+            options.set_inline(ScrollLogicalPosition::Start);
+            button.scroll_into_view_with_scroll_into_view_options(&options);
         }
     });
     view! {
@@ -135,7 +146,7 @@ fn DatePickerMenuButton(#[prop(into)] value: String, selected: bool) -> impl Int
         >
             <md-ripple></md-ripple>
             <div class="date-picker-menu-icon">
-                {if selected { Some(view! { <Icon name="check"/> }) } else { None }}
+                {if selected { Some(view! { <Icon name=Symbol::Check/> }) } else { None }}
             </div>
             {value}
         </button>
@@ -145,35 +156,93 @@ fn DatePickerMenuButton(#[prop(into)] value: String, selected: bool) -> impl Int
 #[component]
 fn DayNumber(
     date: NaiveDate,
-    date_picker_current_selected_date: RwSignal<NaiveDate>,
+    date_picker_current_selected_date: RwSignal<Option<NaiveDate>>,
+    /// Date holding the grid's roving tab stop. Only this day is reachable with Tab; arrow keys move it.
+    focused_date: RwSignal<NaiveDate>,
+    tab_stop_date: Signal<NaiveDate>,
+    /// Whether keyboard focus is currently inside the date grid.
+    grid_has_focus: RwSignal<bool>,
     set_currently_viewed_month: WriteSignal<chrono::Month>,
+    set_currently_viewed_year: WriteSignal<u32>,
     is_outside_month: bool,
+    decoration: Option<DayDecoration>,
+    /// Called when the user picks this day.
+    on_select: Callback<NaiveDate>,
 ) -> impl IntoView {
+    let button_node_ref = create_node_ref::<Button>();
     let today = Local::now();
     let is_today: bool = today.date_naive() == date;
-    let is_selected = move || date_picker_current_selected_date.get() == date;
+    let is_selected = move || date_picker_current_selected_date.get() == Some(date);
+    let is_tab_stop = move || tab_stop_date.get() == date;
+    // Follow the roving tab stop with DOM focus, but never steal focus from outside the grid.
+    create_effect(move |_| {
+        if is_tab_stop() && grid_has_focus.get_untracked() {
+            if let Some(button) = button_node_ref.get() {
+                let _ = button.focus();
+            }
+        }
+    });
     view! {
         <button
             type="button"
+            role="gridcell"
             class="day-number md-typescale-body-large"
             class:day-outside-month=is_outside_month
             class:day-today=is_today
             class:day-selected=is_selected
-            on:mousedown=move |_| {
+            tabindex=move || if is_tab_stop() { "0" } else { "-1" }
+            aria-selected=move || is_selected().to_string()
+            aria-current=is_today.then_some("date")
+            aria-label=date.format("%A, %B %-d, %Y").to_string()
+            node_ref=button_node_ref
+            // Click is also fired by Enter and Space on a focused button.
+            on:click=move |_| {
                 if is_outside_month {
-                    set_currently_viewed_month.set(Month::try_from(date.month() as u8).unwrap());
+                    batch(|| {
+                        set_currently_viewed_year.set(date.year_ce().1);
+                        set_currently_viewed_month.set(Month::try_from(date.month() as u8).unwrap());
+                    });
                 }
-                date_picker_current_selected_date.set(date);
+                focused_date.set(date);
+                date_picker_current_selected_date.set(Some(date));
+                on_select.call(date);
             }
         >
 
             <md-ripple></md-ripple>
 
             {date.day()}
+            {decoration.map(DayDecoration::into_view)}
         </button>
     }
 }
 
+/// Marker rendered in a day cell of the [`DatePicker`] grid, e.g. to show which days have events.
+#[derive(Clone)]
+pub enum DayDecoration {
+    /// Small dot below the day number.
+    Dot,
+    /// Short text, such as an event count, in the corner of the day.
+    Badge(String),
+    /// Any view, positioned below the day number.
+    Custom(ViewFn),
+}
+
+impl IntoView for DayDecoration {
+    fn into_view(self) -> View {
+        match self {
+            DayDecoration::Dot => view! { <span class="day-decoration-dot"></span> }.into_view(),
+            DayDecoration::Badge(text) => {
+                view! { <span class="day-decoration-badge md-typescale-label-small">{text}</span> }
+                    .into_view()
+            }
+            DayDecoration::Custom(view) => {
+                view! { <span class="day-decoration-custom">{view.run()}</span> }.into_view()
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum DatePickerState {
     SelectDay,
@@ -182,30 +251,242 @@ pub enum DatePickerState {
 }
 
 const YEARS_TO_SHOW_BEFORE_AFTER_CURRENT: u32 = 10;
+/// Horizontal distance a touch has to travel on the date grid to change the month.
+const SWIPE_THRESHOLD_PX: i32 = 50;
+
+/// Element a [`DatePickerMode::Popover`] is positioned against.
+/// Created from the `NodeRef` of any element, e.g. `DatePickerMode::Popover(text_field_ref.into())`.
+#[derive(Clone, Copy)]
+pub struct PopoverAnchor(Signal<Option<HtmlElement<AnyElement>>>);
+
+impl<T: ElementDescriptor + Clone + 'static> From<NodeRef<T>> for PopoverAnchor {
+    fn from(node_ref: NodeRef<T>) -> Self {
+        PopoverAnchor(Signal::derive(move || {
+            node_ref.get().map(|element| element.into_any())
+        }))
+    }
+}
+
+/// How the DatePicker is presented on the page.
+#[derive(Clone, Copy, Default)]
+pub enum DatePickerMode {
+    /// Rendered in place while `active` is true.
+    #[default]
+    Docked,
+    /// Rendered over a scrim while `active` is true. Focus is trapped inside the picker,
+    /// page scrolling is locked, and Escape or clicking the scrim cancels.
+    Modal,
+    /// Rendered floating below the anchor element while `active` is true, following it as the page scrolls.
+    /// Escape or clicking outside the picker and anchor cancels.
+    Popover(PopoverAnchor),
+    /// Always visible calendar without the Cancel/OK footer. `on_confirm` is called as soon as the user selects a day.
+    Inline,
+}
+
+#[component]
+fn DatePickerDialog(active: RwSignal<bool>, children: Children) -> impl IntoView {
+    let dialog_node_ref = create_node_ref::<Dialog>();
+    let previous_body_overflow = Rc::new(RefCell::new(None));
+    create_effect({
+        let previous_body_overflow = previous_body_overflow.clone();
+        move |_| {
+            if let Some(dialog) = dialog_node_ref.get() {
+                // A modal <dialog> draws the scrim and makes the rest of the page inert, which traps focus.
+                let _ = dialog.show_modal();
+                if let Some(body) = document().body() {
+                    let style = body.style();
+                    *previous_body_overflow.borrow_mut() = style.get_property_value("overflow").ok();
+                    let _ = style.set_property("overflow", "hidden");
+                }
+            }
+        }
+    });
+    on_cleanup(move || {
+        if let Some(overflow) = previous_body_overflow.take() {
+            if let Some(body) = document().body() {
+                let _ = body.style().set_property("overflow", &overflow);
+            }
+        }
+    });
+    view! {
+        <dialog
+            class="leptos-material-datepicker-dialog"
+            node_ref=dialog_node_ref
+            on:cancel=move |event: Event| {
+                event.prevent_default();
+                active.set(false);
+            }
+
+            // Clicks on the scrim target the <dialog> itself.
+            on:click=move |event: MouseEvent| {
+                if event.target() == event.current_target() {
+                    active.set(false);
+                }
+            }
+        >
+
+            {children()}
+        </dialog>
+    }
+}
+
+#[component]
+fn DatePickerPopover(
+    active: RwSignal<bool>,
+    anchor: PopoverAnchor,
+    children: Children,
+) -> impl IntoView {
+    let popover_node_ref = create_node_ref::<Div>();
+    let position = create_rw_signal(None::<(f64, f64)>);
+    let update_position = move || {
+        if let Some(anchor) = anchor.0.get_untracked() {
+            let rect = anchor.get_bounding_client_rect();
+            position.set(Some((rect.bottom(), rect.left())));
+        }
+    };
+    create_effect(move |_| {
+        anchor.0.track();
+        update_position();
+    });
+    let listeners = Rc::new(RefCell::new(
+        Vec::<(&'static str, Closure<dyn Fn(Event)>)>::new(),
+    ));
+    create_effect({
+        let listeners = listeners.clone();
+        move |_| {
+            // Follow the anchor as the page, or any element containing it, is scrolled or resized.
+            let reposition = || Closure::<dyn Fn(Event)>::new(move |_| update_position());
+            // Clicks on the anchor are left to it, so a button toggling the picker doesn't reopen it.
+            let dismiss = Closure::<dyn Fn(Event)>::new(move |event: Event| {
+                let target = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Node>().ok());
+                let contains_target =
+                    |node: Option<Node>| node.is_some_and(|node| node.contains(target.as_ref()));
+                let popover = popover_node_ref
+                    .get_untracked()
+                    .map(|popover| (*popover).clone().into());
+                let anchor = anchor
+                    .0
+                    .get_untracked()
+                    .map(|anchor| (*anchor).clone().into());
+                if !contains_target(popover) && !contains_target(anchor) {
+                    active.set(false);
+                }
+            });
+            let mut listeners = listeners.borrow_mut();
+            for (event_name, listener) in [
+                ("scroll", reposition()),
+                ("resize", reposition()),
+                ("pointerdown", dismiss),
+            ] {
+                let _ = window().add_event_listener_with_callback_and_bool(
+                    event_name,
+                    listener.as_ref().unchecked_ref(),
+                    true,
+                );
+                listeners.push((event_name, listener));
+            }
+        }
+    });
+    // Listeners are only added in the browser, so there's nothing to remove after rendering on the server.
+    on_cleanup(move || {
+        for (event_name, listener) in listeners.take() {
+            let _ = window().remove_event_listener_with_callback_and_bool(
+                event_name,
+                listener.as_ref().unchecked_ref(),
+                true,
+            );
+        }
+    });
+    let style = move || match position.get() {
+        Some((top, left)) => format!("top: {}px; left: {}px;", top, left),
+        None => "visibility: hidden;".to_string(),
+    };
+    view! {
+        <div class="leptos-material-datepicker-popover" node_ref=popover_node_ref style=style>
+            {children()}
+        </div>
+    }
+}
 
 /// This is a native Leptos component.
 /// See [material.io](https://m3.material.io/components/date-pickers/overview).
 #[component]
 pub fn DatePicker(
+    /// Whether the picker is shown. Ignored by [`DatePickerMode::Inline`], which is always shown.
+    #[prop(optional)]
     active: RwSignal<bool>,
+    #[prop(optional)] mode: DatePickerMode,
     #[prop(optional)] starting_date: Option<NaiveDate>,
+    /// Controlled date. The picker follows changes to this signal, writes the confirmed date to it,
+    /// and offers a Clear action which sets it to `None`. Takes precedence over `starting_date`.
+    #[prop(optional)]
+    value: Option<RwSignal<Option<NaiveDate>>>,
+    /// Adds a marker, such as an event dot or badge, to a day in the grid.
+    #[prop(optional, into)]
+    decorate_day: Option<Callback<NaiveDate, Option<DayDecoration>>>,
+    /// Adds a column with the ISO week number of each row.
+    #[prop(optional, into)]
+    show_week_numbers: MaybeSignal<bool>,
     on_confirm: impl FnMut(NaiveDate) + Clone + 'static,
 ) -> impl IntoView {
+    let is_controlled = value.is_some();
+    let value = value.unwrap_or_else(|| create_rw_signal(starting_date));
     let state = create_rw_signal(DatePickerState::SelectDay);
-    let selected_date = create_rw_signal(if let Some(date) = starting_date {
+    let selected_date = create_rw_signal(value.get_untracked());
+    let viewed_date = selected_date
+        .get_untracked()
+        .unwrap_or_else(|| Local::now().date_naive());
+    let current_month =
+        create_rw_signal(chrono::Month::try_from(viewed_date.month() as u8).unwrap());
+    let current_year = create_rw_signal(viewed_date.year_ce().1);
+    let focused_date = create_rw_signal(viewed_date);
+    let last_rendered_month = store_value(None::<NaiveDate>);
+    // Only set the year and month when they change, since every set rebuilds the month grid.
+    let view_month_of = move |date: NaiveDate| {
+        let year = date.year_ce().1;
+        let month = Month::try_from(date.month() as u8).unwrap();
+        if current_year.get_untracked() != year {
+            current_year.set(year);
+        }
+        if current_month.get_untracked() != month {
+            current_month.set(month);
+        }
+    };
+    // Show the committed value, or today, whenever the picker is opened or the value is changed elsewhere.
+    let reset_to_value = move || {
+        let date = value.get_untracked();
+        let viewed_date = date.unwrap_or_else(|| Local::now().date_naive());
+        last_rendered_month.set_value(None);
+        batch(|| {
+            selected_date.set(date);
+            focused_date.set(viewed_date);
+            view_month_of(viewed_date);
+            state.set(DatePickerState::SelectDay);
+        });
+    };
+    create_effect(move |previous_value: Option<Option<NaiveDate>>| {
+        let date = value.get();
+        if previous_value.is_some() {
+            reset_to_value();
+        }
         date
-    } else {
-        Local::now().date_naive()
     });
-    let current_month = create_rw_signal(
-        chrono::Month::try_from(selected_date.get_untracked().month() as u8).unwrap(),
-    );
-    let current_year = create_rw_signal(selected_date.get_untracked().year_ce().1);
+    let grid_has_focus = create_rw_signal(false);
+    let is_inline = matches!(mode, DatePickerMode::Inline);
+    // Inline pickers commit a day as soon as the user picks it. Changes to `value` from elsewhere don't call `on_confirm`.
+    let on_day_selected = {
+        let confirm = RefCell::new(on_confirm.clone());
+        Callback::new(move |date: NaiveDate| {
+            if is_inline && value.get_untracked() != Some(date) {
+                value.set(Some(date));
+                (confirm.borrow_mut())(date);
+            }
+        })
+    };
     let days_in_month =
         Signal::derive(move || days_in_year_month(current_year.get() as i32, current_month.get()));
-    let days_in_last_month = Signal::derive(move || {
-        days_in_year_month(current_year.get() as i32, current_month.get().pred())
-    });
     let first_day_of_month = Signal::derive(move || {
         NaiveDate::from_ymd_opt(
             current_year.get() as i32,
@@ -214,24 +495,35 @@ pub fn DatePicker(
         )
         .unwrap()
     });
+    // Keep the tab stop inside the viewed month, even after the month is changed with the header buttons.
+    let tab_stop_date = Signal::derive(move || {
+        let focused = focused_date.get();
+        let first_day = first_day_of_month.get();
+        if focused.year() == first_day.year() && focused.month() == first_day.month() {
+            focused
+        } else {
+            first_day
+        }
+    });
+    // Days before and after the viewed month are counted from its first day, so they're dated in the right year in January and December.
     let last_month_day_buttons = move || {
-        let prev_month = current_month.get().pred();
-        (0..(first_day_of_month.get().weekday().num_days_from_sunday()))
+        let first_day = first_day_of_month.get();
+        (1..=first_day.weekday().num_days_from_sunday())
             .rev()
-            .map(move |number_of_days_since_sunday| {
-                let day = days_in_last_month.get() - number_of_days_since_sunday;
-                let date = NaiveDate::from_ymd_opt(
-                    current_year.get() as i32,
-                    prev_month.number_from_month(),
-                    day,
-                )
-                .unwrap();
+            .map(move |days_before_first_day| {
+                let date = first_day - Days::new(days_before_first_day.into());
                 view! {
                     <DayNumber
                         date=date
                         date_picker_current_selected_date=selected_date
+                        focused_date=focused_date
+                        tab_stop_date=tab_stop_date
+                        grid_has_focus=grid_has_focus
                         set_currently_viewed_month=current_month.write_only()
+                        set_currently_viewed_year=current_year.write_only()
                         is_outside_month=true
+                        decoration=decorate_day.and_then(|decorate| decorate.call(date))
+                        on_select=on_day_selected
                     />
                 }
             })
@@ -248,44 +540,124 @@ pub fn DatePicker(
                 <DayNumber
                     date=date
                     date_picker_current_selected_date=selected_date
+                    focused_date=focused_date
+                    tab_stop_date=tab_stop_date
+                    grid_has_focus=grid_has_focus
                     set_currently_viewed_month=current_month.write_only()
+                    set_currently_viewed_year=current_year.write_only()
                     is_outside_month=false
+                    decoration=decorate_day.and_then(|decorate| decorate.call(date))
+                    on_select=on_day_selected
                 />
             }
         })
     };
     let next_month_day_buttons = move || {
-        let last_day_of_month = NaiveDate::from_ymd_opt(
-            current_year.get() as i32,
-            current_month.get().number_from_month(),
-            days_in_month.get(),
-        )
-        .unwrap();
-        let next_month = current_month.get().succ();
+        let first_day_of_next_month = first_day_of_month.get() + Months::new(1);
         let remaining_weekdays_after_current_month =
-            7 - last_day_of_month.weekday().number_from_sunday();
-        (1..remaining_weekdays_after_current_month + 1).map(move |day| {
-            let date = NaiveDate::from_ymd_opt(
-                current_year.get() as i32,
-                next_month.number_from_month(),
-                day,
-            )
-            .unwrap();
+            (7 - first_day_of_next_month.weekday().num_days_from_sunday()) % 7;
+        (0..remaining_weekdays_after_current_month).map(move |days_after_month| {
+            let date = first_day_of_next_month + Days::new(days_after_month.into());
             view! {
                 <DayNumber
                     date=date
                     date_picker_current_selected_date=selected_date
+                    focused_date=focused_date
+                    tab_stop_date=tab_stop_date
+                    grid_has_focus=grid_has_focus
                     set_currently_viewed_month=current_month.write_only()
+                    set_currently_viewed_year=current_year.write_only()
                     is_outside_month=true
+                    decoration=decorate_day.and_then(|decorate| decorate.call(date))
+                    on_select=on_day_selected
                 />
             }
         })
     };
-    let days = move || {
-        last_month_day_buttons()
+    let weeks = move || {
+        // Slide the new month in from the side it's coming from.
+        let first_day = first_day_of_month.get();
+        let slide_direction = last_rendered_month
+            .get_value()
+            .map(|last_rendered_first_day| first_day.cmp(&last_rendered_first_day));
+        last_rendered_month.set_value(Some(first_day));
+        let days: Vec<View> = last_month_day_buttons()
             .chain(day_buttons())
             .chain(next_month_day_buttons())
-            .collect_view()
+            .map(IntoView::into_view)
+            .collect();
+        let first_sunday_in_grid = first_day_of_month.get()
+            - Days::new(first_day_of_month.get().weekday().num_days_from_sunday().into());
+        let rows = days
+            .chunks(7)
+            .zip(first_sunday_in_grid.iter_weeks())
+            .map(|(week, sunday)| {
+                // ISO weeks start on Monday, so number each Sunday-first row by its Monday.
+                let week_number = show_week_numbers.get().then(|| {
+                    let iso_week = sunday.succ_opt().unwrap().iso_week().week();
+                    view! {
+                        <p
+                            class="week-number md-typescale-body-small"
+                            role="rowheader"
+                            aria-label=format!("Week {iso_week}")
+                        >
+                            {iso_week}
+                        </p>
+                    }
+                });
+                view! { <div role="row" class="date-grid-row">{week_number} {week.to_vec()}</div> }
+            })
+            .collect_view();
+        view! {
+            <div
+                class="date-grid-days"
+                class:slide-from-start=slide_direction == Some(Ordering::Less)
+                class:slide-from-end=slide_direction == Some(Ordering::Greater)
+                role="rowgroup"
+            >
+                {rows}
+            </div>
+        }
+    };
+    let on_grid_keydown = move |event: KeyboardEvent| {
+        let focused = tab_stop_date.get_untracked();
+        let months = if event.shift_key() { 12 } else { 1 };
+        let next_focused = match event.key().as_str() {
+            "ArrowLeft" => focused.pred_opt(),
+            "ArrowRight" => focused.succ_opt(),
+            "ArrowUp" => focused.checked_sub_days(Days::new(7)),
+            "ArrowDown" => focused.checked_add_days(Days::new(7)),
+            "Home" => focused.checked_sub_days(Days::new(
+                focused.weekday().num_days_from_sunday().into(),
+            )),
+            "End" => focused.checked_add_days(Days::new(
+                (6 - focused.weekday().num_days_from_sunday()).into(),
+            )),
+            "PageUp" => focused.checked_sub_months(Months::new(months)),
+            "PageDown" => focused.checked_add_months(Months::new(months)),
+            _ => return,
+        };
+        event.prevent_default();
+        if let Some(date) = next_focused {
+            batch(|| {
+                view_month_of(date);
+                focused_date.set(date);
+            });
+        }
+    };
+    let on_grid_focusout = move |event: FocusEvent| {
+        let focus_stays_in_grid = event.related_target().is_some_and(|target| {
+            event
+                .current_target()
+                .and_then(|grid| grid.dyn_into::<Node>().ok())
+                .is_some_and(|grid| grid.contains(target.dyn_ref::<Node>()))
+        });
+        if !focus_stays_in_grid {
+            grid_has_focus.set(false);
+        }
+    };
+    let viewed_month_label = move || {
+        format!("{} {}", current_month.get().name(), current_year.get())
     };
     let month_options = move || {
         let current_month_num = current_month.get().number_from_month();
@@ -331,27 +703,112 @@ pub fn DatePicker(
     let abbreviated_current_month_name =
         Signal::derive(move || get_abbreviated_month_name(&current_month.get()).to_string());
     let year_str = Signal::derive(move || current_year.get().to_string());
-    let decrement_month_on_click = move |_: MouseEvent| {
+    let show_previous_month = move || {
         if current_month.get() == Month::January {
             current_year.set(current_year.get() - 1);
         }
         current_month.set(current_month.get().pred());
     };
-    let increment_month_on_click = move |_: MouseEvent| {
+    let show_next_month = move || {
         if current_month.get() == Month::December {
             current_year.set(current_year.get() + 1);
         }
         current_month.set(current_month.get().succ());
     };
+    let decrement_month_on_click = move |_: MouseEvent| show_previous_month();
+    let increment_month_on_click = move |_: MouseEvent| show_next_month();
+    let swipe_start_x = store_value(None::<i32>);
+    let on_grid_pointerdown = move |event: PointerEvent| {
+        // Mice already have the arrow buttons; only track touch and pen swipes.
+        if event.pointer_type() != "mouse" {
+            swipe_start_x.set_value(Some(event.client_x()));
+        }
+    };
+    let on_grid_pointerup = move |event: PointerEvent| {
+        if let Some(start_x) = swipe_start_x.get_value() {
+            swipe_start_x.set_value(None);
+            let distance = event.client_x() - start_x;
+            if distance <= -SWIPE_THRESHOLD_PX {
+                show_next_month();
+            } else if distance >= SWIPE_THRESHOLD_PX {
+                show_previous_month();
+            }
+        }
+    };
     let decrement_year_on_click = move |_: MouseEvent| {
         current_year.set(current_year.get() - 1);
     };
     let increment_year_on_click = move |_: MouseEvent| {
         current_year.set(current_year.get() + 1);
     };
-    view! {
-        <Show when=move || active.get()>
-            <div class="leptos-material-datepicker">
+    let surface_role = matches!(mode, DatePickerMode::Docked | DatePickerMode::Popover(_))
+        .then_some("dialog");
+    let picker = move || {
+        reset_to_value();
+        let footer = (!is_inline).then(|| {
+            let clear_button = is_controlled.then(|| {
+                view! {
+                    <Button
+                        button_type=ButtonType::Button
+                        attr:class="datepicker-footer-clear"
+                        on:click=move |_| {
+                            active.set(false);
+                            value.set(None);
+                        }
+
+                        style=ButtonStyle::Text
+                    >
+                        "Clear"
+                    </Button>
+                }
+            });
+            view! {
+                <div class="datepicker-footer">
+                    {clear_button}
+                    <Button
+                        button_type=ButtonType::Button
+                        on:click=move |_| {
+                            active.set(false);
+                        }
+
+                        style=ButtonStyle::Text
+                    >
+                        "Cancel"
+                    </Button>
+                    <Button
+                        button_type=ButtonType::Button
+                        on:click={
+                            let mut confirm = on_confirm.clone();
+                            move |_| {
+                                active.set(false);
+                                if let Some(date) = selected_date.get() {
+                                    value.set(Some(date));
+                                    confirm(date);
+                                }
+                            }
+                        }
+
+                        style=ButtonStyle::Text
+                    >
+                        "OK"
+                    </Button>
+                </div>
+            }
+        });
+        view! {
+            <div
+                class="leptos-material-datepicker"
+                class:inline=is_inline
+                class:with-week-numbers=move || show_week_numbers.get()
+                role=surface_role
+                aria-label="Select date"
+                on:keydown=move |event: KeyboardEvent| {
+                    if event.key() == "Escape" {
+                        event.prevent_default();
+                        active.set(false);
+                    }
+                }
+            >
                 <Elevation/>
                 <div class="datepicker-header">
                     <DatePickerHeaderButton
@@ -372,18 +829,40 @@ pub fn DatePicker(
                 {move || match state.get() {
                     DatePickerState::SelectDay => {
                         view! {
-                            <div class="date-grid">
-
-                                <div class="days-of-week">
-                                    <p class="md-typescale-body-large">{"S"}</p>
-                                    <p class="md-typescale-body-large">{"M"}</p>
-                                    <p class="md-typescale-body-large">{"T"}</p>
-                                    <p class="md-typescale-body-large">{"W"}</p>
-                                    <p class="md-typescale-body-large">{"T"}</p>
-                                    <p class="md-typescale-body-large">{"F"}</p>
-                                    <p class="md-typescale-body-large">{"S"}</p>
+                            <div
+                                class="date-grid"
+                                role="grid"
+                                aria-label=viewed_month_label
+                                on:keydown=on_grid_keydown
+                                on:focusin=move |_| grid_has_focus.set(true)
+                                on:focusout=on_grid_focusout
+                                on:pointerdown=on_grid_pointerdown
+                                on:pointerup=on_grid_pointerup
+                                on:pointercancel=move |_| swipe_start_x.set_value(None)
+                            >
+
+                                <div class="days-of-week" role="row">
+                                    {show_week_numbers
+                                        .get()
+                                        .then(|| {
+                                            view! {
+                                                <p class="md-typescale-body-small" role="columnheader" aria-label="Week">
+                                                    {"Wk"}
+                                                </p>
+                                            }
+                                        })}
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Sunday">{"S"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Monday">{"M"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Tuesday">{"T"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Wednesday">{"W"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Thursday">{"T"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Friday">{"F"}</p>
+                                    <p class="md-typescale-body-large" role="columnheader" aria-label="Saturday">{"S"}</p>
                                 </div>
-                                {days}
+                                {weeks}
+                            </div>
+                            <div class="leptos-material-visually-hidden" aria-live="polite">
+                                {viewed_month_label}
                             </div>
                         }
                             .into_view()
@@ -396,34 +875,37 @@ pub fn DatePicker(
                     }
                 }}
 
-                <div class="datepicker-footer">
-                    <Button
-                        button_type=ButtonType::Button
-                        on:click=move |_| {
-                            active.set(false);
-                        }
-
-                        style=ButtonStyle::Text
-                    >
-                        "Cancel"
-                    </Button>
-                    <Button
-                        button_type=ButtonType::Button
-                        on:click={
-                            let mut confirm = on_confirm.clone();
-                            move |_| {
-                                active.set(false);
-                                confirm(selected_date.get());
-                            }
-                        }
-
-                        style=ButtonStyle::Text
-                    >
-                        "OK"
-                    </Button>
-                </div>
+                {footer}
 
             </div>
-        </Show>
+        }
+    };
+    match mode {
+        DatePickerMode::Docked => view! { <Show when=move || active.get()>{picker()}</Show> },
+        DatePickerMode::Modal => {
+            view! {
+                <Show when=move || active.get()>
+                    {
+                        let picker = picker.clone();
+                        view! { <DatePickerDialog active=active>{picker()}</DatePickerDialog> }
+                    }
+                </Show>
+            }
+        }
+        DatePickerMode::Popover(anchor) => {
+            view! {
+                <Show when=move || active.get()>
+                    {
+                        let picker = picker.clone();
+                        view! {
+                            <DatePickerPopover active=active anchor=anchor>
+                                {picker()}
+                            </DatePickerPopover>
+                        }
+                    }
+                </Show>
+            }
+        }
+        DatePickerMode::Inline => picker().into_view(),
     }
 }
@@ -1,7 +1,25 @@
-use leptos::{component, view, IntoView};
+use leptos::{component, view, IntoView, MaybeProp, SignalGet};
 
+use crate::lazy::load_component_module;
+
+/// The highest elevation level in Material 3.
+pub const MAX_ELEVATION_LEVEL: u8 = 5;
+
+/// Shadow of the parent element, which should have `position: relative`.
+/// Changes of level animate, so a signal can raise a surface while it's hovered, pressed or dragged.
 /// See the [MWC Github](https://github.com/material-components/material-web/blob/main/docs/components/elevation.md).
 #[component]
-pub fn Elevation() -> impl IntoView {
-    view! { <md-elevation></md-elevation> }
+pub fn Elevation(
+    /// Elevation level from 0, no shadow, to 5. Higher levels are clamped to 5.
+    /// Without a level, the `--md-elevation-level` CSS property set on a parent element is used.
+    #[prop(optional, into)]
+    level: MaybeProp<u8>,
+) -> impl IntoView {
+    load_component_module("elevation");
+    let style = move || {
+        level
+            .get()
+            .map(|level| format!("--md-elevation-level: {};", level.min(MAX_ELEVATION_LEVEL)))
+    };
+    view! { <md-elevation style=style></md-elevation> }
 }
@@ -0,0 +1,141 @@
+use leptos::{
+    component, html, Attribute, Children, IntoAttribute, IntoView, MaybeProp, MaybeSignal,
+};
+
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::lazy::load_component_module;
+use crate::link::link;
+use crate::swap::swappable_element;
+
+/// `Branded` FABs show a multi-colored logo, such as a product icon, instead of a Material Symbol.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum FabStyle {
+    #[default]
+    Fab,
+    Branded,
+}
+
+impl FabStyle {
+    pub fn get_element_name(&self) -> String {
+        match self {
+            FabStyle::Fab => "md-fab",
+            FabStyle::Branded => "md-branded-fab",
+        }
+        .into()
+    }
+}
+
+/// Color of a FAB. Branded FABs are always `Surface`.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum FabVariant {
+    #[default]
+    Surface,
+    Primary,
+    Secondary,
+    Tertiary,
+}
+
+impl From<FabVariant> for String {
+    fn from(value: FabVariant) -> Self {
+        match value {
+            FabVariant::Surface => "surface",
+            FabVariant::Primary => "primary",
+            FabVariant::Secondary => "secondary",
+            FabVariant::Tertiary => "tertiary",
+        }
+        .into()
+    }
+}
+
+impl IntoAttribute for FabVariant {
+    fn into_attribute(self) -> Attribute {
+        Attribute::String(String::from(self).into())
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        Attribute::String(String::from(*self).into())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum FabSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl From<FabSize> for String {
+    fn from(value: FabSize) -> Self {
+        match value {
+            FabSize::Small => "small",
+            FabSize::Medium => "medium",
+            FabSize::Large => "large",
+        }
+        .into()
+    }
+}
+
+impl IntoAttribute for FabSize {
+    fn into_attribute(self) -> Attribute {
+        Attribute::String(String::from(self).into())
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        Attribute::String(String::from(*self).into())
+    }
+}
+
+element_ref! {
+    /// Handle for calling methods on a [`Fab`] element, passed with the `element_ref` prop.
+    /// Methods do nothing while the FAB isn't mounted.
+    FabRef(web_sys::HtmlElement)
+}
+
+/// Pass the icon as a child with `element_slot="icon"`.
+/// [MWC Docs](https://material-web.dev/components/fab/)
+#[component]
+pub fn Fab(
+    /// Changing the style swaps the element, keeping the FAB's children.
+    #[prop(optional, into)]
+    style: MaybeSignal<FabStyle>,
+    #[prop(optional, into)] variant: MaybeSignal<FabVariant>,
+    #[prop(optional, into)] size: MaybeSignal<FabSize>,
+    /// Text shown next to the icon, making this an extended FAB.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// Use a lower elevation, for FABs shown over other surfaces.
+    #[prop(optional, into)]
+    lowered: MaybeSignal<bool>,
+    /// Accessible name of the FAB when it has no `label`.
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+    /// Navigate to this URL when clicked. With the `router` feature, internal links navigate client-side.
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+    /// Where to open `href`, e.g. `_blank`.
+    #[prop(optional, into)]
+    target: MaybeProp<String>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
+    #[prop(optional)] children: Option<Children>,
+    #[prop(optional)] element_ref: FabRef,
+) -> impl IntoView {
+    load_component_module("fab");
+    let children = children.map(|children| children());
+    swappable_element(style, FabStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "variant", variant)
+            .typed_attr(&attr, "size", size)
+            .typed_attr(&attr, "label", label.clone())
+            .typed_attr(&attr, "lowered", lowered)
+            .typed_attr(&attr, "aria-label", aria_label.clone());
+        // md-fab doesn't render an anchor, so it navigates on click instead.
+        link(html, href.clone(), target.clone(), false, &attr)
+            .attrs(attr.clone())
+            .child(children.clone())
+    })
+}
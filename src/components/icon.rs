@@ -1,13 +1,197 @@
-use leptos::{component, view, IntoView};
+use std::{collections::HashMap, rc::Rc};
+
+use leptos::{
+    component, html, use_context, IntoView, Memo, MaybeProp, MaybeSignal, ReadSignal,
+    RwSignal, Signal, SignalGet,
+};
+
+use crate::lazy::load_component_module;
+
+include!(concat!(env!("OUT_DIR"), "/symbols.rs"));
+
+/// The Material Symbols font used to draw an icon. All three are loaded by `UseMaterialWebComponents`,
+/// but a family's font is only downloaded once an icon uses it.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum IconFamily {
+    #[default]
+    Outlined,
+    Rounded,
+    Sharp,
+}
+
+impl IconFamily {
+    pub fn get_font_name(&self) -> String {
+        match self {
+            IconFamily::Outlined => "Material Symbols Outlined",
+            IconFamily::Rounded => "Material Symbols Rounded",
+            IconFamily::Sharp => "Material Symbols Sharp",
+        }
+        .into()
+    }
+}
+
+/// What an [`Icon`] draws. Strings and string signals convert to [`IconSource::Name`],
+/// and [`Symbol`]s to [`IconSource::Symbol`].
+#[derive(Clone)]
+pub enum IconSource {
+    /// A Material Symbols icon, checked at compile time. Drawn by its codepoint, so it doesn't rely on font ligatures.
+    Symbol(MaybeSignal<Symbol>),
+    /// Any Material Symbols icon name, e.g. `home`. Drawn with a font ligature.
+    Name(MaybeSignal<String>),
+    /// A Material Symbols codepoint, for icons without a [`Symbol`].
+    Codepoint(char),
+    /// Inline SVG markup, e.g. from `include_str!("logo.svg")`. The SVG is filled with the icon color.
+    Svg(MaybeSignal<String>),
+    /// An SVG added to the [`IconRegistry`] under this name.
+    Registered(MaybeSignal<String>),
+}
+
+impl From<Symbol> for IconSource {
+    fn from(value: Symbol) -> Self {
+        IconSource::Symbol(value.into())
+    }
+}
+
+impl From<Signal<Symbol>> for IconSource {
+    fn from(value: Signal<Symbol>) -> Self {
+        IconSource::Symbol(value.into())
+    }
+}
+
+impl From<&str> for IconSource {
+    fn from(value: &str) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+impl From<String> for IconSource {
+    fn from(value: String) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+impl From<MaybeSignal<String>> for IconSource {
+    fn from(value: MaybeSignal<String>) -> Self {
+        IconSource::Name(value)
+    }
+}
+
+impl From<ReadSignal<String>> for IconSource {
+    fn from(value: ReadSignal<String>) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+impl From<RwSignal<String>> for IconSource {
+    fn from(value: RwSignal<String>) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+impl From<Memo<String>> for IconSource {
+    fn from(value: Memo<String>) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+impl From<Signal<String>> for IconSource {
+    fn from(value: Signal<String>) -> Self {
+        IconSource::Name(value.into())
+    }
+}
+
+/// SVG icons, such as brand logos, that [`Icon`] can draw by name with [`IconSource::Registered`].
+/// Provide it with `provide_context` near the root of the app:
+/// ```ignore
+/// provide_context(IconRegistry::new().with("github", include_str!("icons/github.svg")));
+/// view! { <Icon name=IconSource::Registered("github".into())/> }
+/// ```
+#[derive(Clone, Default)]
+pub struct IconRegistry(Rc<HashMap<String, String>>);
+
+impl IconRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an icon drawn from the SVG markup `svg`.
+    pub fn with(mut self, name: impl Into<String>, svg: impl Into<String>) -> Self {
+        Rc::make_mut(&mut self.0).insert(name.into(), svg.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.0.get(name).cloned()
+    }
+}
 
 /// Icon element. See [Material Symbols](https://fonts.google.com/icons) for icon options.
-/// Icon names are `snake_case`.
+/// Icon names are `snake_case`. Prefer [`icon!`](crate::icon) over strings, so misspelled names fail to compile:
+/// ```ignore
+/// view! { <Icon name=icon!(chevron_left)/> }
+/// ```
 #[component]
 pub fn Icon(
-    /// Icon name. See [Material Symbols](https://fonts.google.com/icons) for icon options.
+    /// Icon name, or any other [`IconSource`]. See [Material Symbols](https://fonts.google.com/icons) for icon options.
     #[prop(into)]
-    name: String,
-    #[prop(optional, into)] element_slot: String,
+    name: IconSource,
+    #[prop(optional, into)] element_slot: MaybeSignal<String>,
+    /// Defaults to the `--md-icon-font` token, which is `Outlined` unless a theme changes it.
+    #[prop(optional, into)]
+    family: MaybeProp<IconFamily>,
+    /// Use the filled, or with `false` the outlined, version of the icon. The font's default is outlined.
+    #[prop(optional, into)]
+    fill: MaybeProp<bool>,
+    /// Stroke weight, from 100 to 700. The font's default is 400.
+    #[prop(optional, into)]
+    weight: MaybeProp<u16>,
+    /// Stroke thickness adjustment, from -50 to 200, e.g. -25 for icons on dark backgrounds. The font's default is 0.
+    #[prop(optional, into)]
+    grade: MaybeProp<i16>,
+    /// The icon size, from 20 to 48, that the design is optimized for. Defaults to the rendered size.
+    #[prop(optional, into)]
+    optical_size: MaybeProp<u8>,
 ) -> impl IntoView {
-    view! { <md-icon slot=element_slot>{name}</md-icon> }
+    load_component_module("icon");
+    // Only props that are set are written, so a theme's `--md-icon-font` and `font-variation-settings` still apply.
+    let style = move || {
+        let mut axes = Vec::new();
+        if let Some(fill) = fill.get() {
+            axes.push(format!("'FILL' {}", u8::from(fill)));
+        }
+        if let Some(weight) = weight.get() {
+            axes.push(format!("'wght' {weight}"));
+        }
+        if let Some(grade) = grade.get() {
+            axes.push(format!("'GRAD' {grade}"));
+        }
+        if let Some(optical_size) = optical_size.get() {
+            axes.push(format!("'opsz' {optical_size}"));
+        }
+        let mut style = Vec::new();
+        if let Some(family) = family.get() {
+            style.push(format!("--md-icon-font: '{}';", family.get_font_name()));
+        }
+        if !axes.is_empty() {
+            style.push(format!("font-variation-settings: {};", axes.join(", ")));
+        }
+        (!style.is_empty()).then(|| style.join(" "))
+    };
+    let icon = move || {
+        html::custom(html::Custom::new("md-icon"))
+            .attr("slot", element_slot.clone())
+            .attr("style", style)
+    };
+    match name {
+        IconSource::Symbol(symbol) => icon()
+            .child(move || symbol.get().codepoint().to_string())
+            .into_view(),
+        IconSource::Name(name) => icon().child(name).into_view(),
+        IconSource::Codepoint(codepoint) => icon().child(codepoint.to_string()).into_view(),
+        IconSource::Svg(svg) => (move || icon().inner_html(svg.get())).into_view(),
+        IconSource::Registered(name) => {
+            let registry = use_context::<IconRegistry>().unwrap_or_default();
+            (move || icon().inner_html(registry.get(&name.get()).unwrap_or_default())).into_view()
+        }
+    }
 }
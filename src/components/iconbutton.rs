@@ -1,8 +1,18 @@
-use leptos::{component, html, Attribute, Children, IntoAttribute, IntoView};
+use leptos::{
+    component, ev, html, view, Attribute, Callback, Children, IntoView, MaybeProp, MaybeSignal,
+    RwSignal, SignalGet, SignalSet,
+};
 
-use super::button::ButtonType;
+use super::button::{loading_indicator, ButtonLoading, ButtonType};
+use super::icon::{Icon, IconSource};
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::events::{on_event, target_flag};
+use crate::lazy::load_component_module;
+use crate::link::link;
+use crate::swap::swappable_element;
 
-#[derive(Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum IconButtonStyle {
     #[default]
     Icon,
@@ -23,16 +33,131 @@ impl IconButtonStyle {
     }
 }
 
+element_ref! {
+    /// Handle for calling methods on an [`IconButton`] or [`ToggleIconButton`] element, passed with the `element_ref` prop.
+    /// Methods do nothing while the button isn't mounted.
+    IconButtonRef(web_sys::HtmlElement)
+}
+
 /// [MWC Docs](https://material-web.dev/components/icon-button/)
 #[component]
 pub fn IconButton(
-    #[prop(optional)] style: IconButtonStyle,
-    #[prop(optional)] button_type: ButtonType,
-    #[prop(attrs)] attr: Vec<(&'static str, Attribute)>,
+    /// Changing the style swaps the element, keeping the button's children.
+    #[prop(optional, into)]
+    style: MaybeSignal<IconButtonStyle>,
+    #[prop(optional, into)] button_type: MaybeSignal<ButtonType>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Disable the button while keeping it focusable, e.g. so a tooltip can explain why it's disabled.
+    #[prop(optional, into)]
+    soft_disabled: MaybeSignal<bool>,
+    /// Disable the button and show a progress indicator in place of its icon, e.g. while a server action runs.
+    /// Accepts an [`Action`](leptos::Action) directly.
+    #[prop(optional, into)]
+    loading: ButtonLoading,
+    /// Accessible name of the button, since it has no visible label.
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+    /// Render the button as a link to this URL. With the `router` feature, internal links navigate client-side.
+    #[prop(optional, into)]
+    href: MaybeProp<String>,
+    /// Where to open `href`, e.g. `_blank`.
+    #[prop(optional, into)]
+    target: MaybeProp<String>,
+    /// Mirror the icon in right-to-left languages, e.g. for arrows.
+    #[prop(optional, into)]
+    flip_icon_in_rtl: MaybeSignal<bool>,
+    /// Name used when submitting the button's form.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    /// Value used when submitting the button's form.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
     children: Children,
+    #[prop(optional)] element_ref: IconButtonRef,
+) -> impl IntoView {
+    load_component_module("iconbutton");
+    let children = children();
+    let loading_indicator = loading_indicator(loading);
+    swappable_element(style, IconButtonStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "type", button_type.clone())
+            .typed_attr(&attr, "disabled", move || disabled.get() || loading.get())
+            .typed_attr(&attr, "soft-disabled", soft_disabled)
+            .typed_attr(&attr, "aria-busy", move || loading.get().then_some("true"))
+            .typed_attr(&attr, "aria-label", aria_label.clone())
+            .typed_attr(&attr, "flip-icon-in-rtl", flip_icon_in_rtl)
+            .typed_attr(&attr, "name", name.clone())
+            .typed_attr(&attr, "value", value.clone());
+        link(html, href.clone(), target.clone(), true, &attr)
+            .attrs(attr.clone())
+            .child(children.clone())
+            .child(loading_indicator.clone())
+    })
+}
+
+/// An icon button that toggles between two icons, such as an outlined and a filled star for a favorite.
+/// The element sets `aria-pressed` from the selected state.
+/// [MWC Docs](https://material-web.dev/components/icon-button/#toggle)
+#[component]
+pub fn ToggleIconButton(
+    /// Changing the style swaps the element.
+    #[prop(optional, into)]
+    style: MaybeSignal<IconButtonStyle>,
+    /// Bound to whether the button is selected. Updated when the user toggles it.
+    selected: RwSignal<bool>,
+    /// Icon shown while unselected, by name or any other [`IconSource`].
+    #[prop(into)]
+    icon: IconSource,
+    /// Icon shown while selected, by name or any other [`IconSource`].
+    #[prop(into)]
+    selected_icon: IconSource,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Disable the button while keeping it focusable, e.g. so a tooltip can explain why it's disabled.
+    #[prop(optional, into)]
+    soft_disabled: MaybeSignal<bool>,
+    /// Accessible name of the button while unselected.
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+    /// Accessible name of the button while selected. Defaults to `aria_label`.
+    #[prop(optional, into)]
+    aria_label_selected: MaybeProp<String>,
+    /// Mirror the icons in right-to-left languages, e.g. for arrows.
+    #[prop(optional, into)]
+    flip_icon_in_rtl: MaybeSignal<bool>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
+    /// Called with the new selected state when the user toggles the button.
+    #[prop(optional, into)]
+    on_change: Option<Callback<bool>>,
+    #[prop(optional)] element_ref: IconButtonRef,
 ) -> impl IntoView {
-    html::custom(html::Custom::new(style.get_element_name()))
-        .attrs(attr)
-        .attr("type", button_type.into_attribute())
-        .child(children())
+    load_component_module("iconbutton");
+    let icons = view! {
+        <Icon name=icon/>
+        <Icon name=selected_icon element_slot="selected"/>
+    };
+    swappable_element(style, IconButtonStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "toggle", true)
+            // The attribute makes the state part of server-rendered HTML, the property keeps it in sync after toggling.
+            .typed_attr(&attr, "selected", move || selected.get())
+            .prop("selected", move || selected.get())
+            .typed_attr(&attr, "disabled", disabled)
+            .typed_attr(&attr, "soft-disabled", soft_disabled)
+            .typed_attr(&attr, "aria-label", aria_label.clone())
+            .typed_attr(&attr, "aria-label-selected", aria_label_selected.clone())
+            .typed_attr(&attr, "flip-icon-in-rtl", flip_icon_in_rtl)
+            .attrs(attr.clone())
+            .on(ev::Custom::<web_sys::Event>::new("change"), move |event| {
+                selected.set(target_flag(&event, "selected"))
+            })
+            .child(icons.clone());
+        on_event(html, "change", on_change, |event| target_flag(event, "selected"))
+    })
 }
@@ -0,0 +1,144 @@
+// Every cargo feature that provides components, in one table. `build.rs` imports each enabled feature's material-web
+// `modules`, and `components/mod.rs` declares its `rust_module`, if it has one.
+// `tags` lists the custom elements the modules define. The build checks that every tag a Rust module renders is
+// imported by an enabled feature, so a missing feature dependency fails the build instead of rendering an unknown element.
+component_manifest! {
+    "button" => {
+        modules: [
+            "button/elevated-button",
+            "button/filled-button",
+            "button/filled-tonal-button",
+            "button/outlined-button",
+            "button/text-button",
+        ],
+        tags: [
+            "md-elevated-button",
+            "md-filled-button",
+            "md-filled-tonal-button",
+            "md-outlined-button",
+            "md-text-button",
+        ],
+        rust_module: button,
+    },
+    "card" => {
+        modules: [],
+        tags: [],
+        rust_module: card,
+    },
+    "checkbox" => {
+        modules: ["checkbox/checkbox"],
+        tags: ["md-checkbox"],
+        rust_module: checkbox,
+    },
+    "chips" => {
+        modules: [
+            "chips/assist-chip",
+            "chips/chip-set",
+            "chips/filter-chip",
+            "chips/input-chip",
+            "chips/suggestion-chip",
+        ],
+        tags: [
+            "md-assist-chip",
+            "md-chip-set",
+            "md-filter-chip",
+            "md-input-chip",
+            "md-suggestion-chip",
+        ],
+        rust_module: chips,
+    },
+    "datepicker" => {
+        modules: [],
+        tags: [],
+        rust_module: datepicker,
+    },
+    "dialog" => {
+        modules: ["dialog/dialog"],
+        tags: ["md-dialog"],
+    },
+    "divider" => {
+        modules: ["divider/divider"],
+        tags: ["md-divider"],
+    },
+    "elevation" => {
+        modules: ["elevation/elevation"],
+        tags: ["md-elevation"],
+        rust_module: elevation,
+    },
+    "fab" => {
+        modules: ["fab/fab", "fab/branded-fab"],
+        tags: ["md-fab", "md-branded-fab"],
+        rust_module: fab,
+    },
+    "focus" => {
+        modules: ["focus/md-focus-ring"],
+        tags: ["md-focus-ring"],
+    },
+    "icon" => {
+        modules: ["icon/icon"],
+        tags: ["md-icon"],
+        rust_module: icon,
+    },
+    "iconbutton" => {
+        modules: [
+            "iconbutton/icon-button",
+            "iconbutton/filled-icon-button",
+            "iconbutton/filled-tonal-icon-button",
+            "iconbutton/outlined-icon-button",
+        ],
+        tags: [
+            "md-icon-button",
+            "md-filled-icon-button",
+            "md-filled-tonal-icon-button",
+            "md-outlined-icon-button",
+        ],
+        rust_module: iconbutton,
+    },
+    "list" => {
+        modules: ["list/list", "list/list-item"],
+        tags: ["md-list", "md-list-item"],
+    },
+    "menu" => {
+        modules: ["menu/menu", "menu/menu-item", "menu/sub-menu"],
+        tags: ["md-menu", "md-menu-item", "md-sub-menu"],
+    },
+    "progress" => {
+        modules: ["progress/circular-progress", "progress/linear-progress"],
+        tags: ["md-circular-progress", "md-linear-progress"],
+        rust_module: progress,
+    },
+    "radio" => {
+        modules: ["radio/radio"],
+        tags: ["md-radio"],
+    },
+    "ripple" => {
+        modules: ["ripple/ripple"],
+        tags: ["md-ripple"],
+    },
+    "select" => {
+        modules: [
+            "select/filled-select",
+            "select/outlined-select",
+            "select/select-option",
+        ],
+        tags: ["md-filled-select", "md-outlined-select", "md-select-option"],
+        rust_module: select,
+    },
+    "slider" => {
+        modules: ["slider/slider"],
+        tags: ["md-slider"],
+    },
+    "switch" => {
+        modules: ["switch/switch"],
+        tags: ["md-switch"],
+    },
+    "tabs" => {
+        modules: ["tabs/tabs", "tabs/primary-tab", "tabs/secondary-tab"],
+        tags: ["md-tabs", "md-primary-tab", "md-secondary-tab"],
+    },
+    "textfield" => {
+        modules: ["textfield/filled-text-field", "textfield/outlined-text-field"],
+        tags: ["md-filled-text-field", "md-outlined-text-field"],
+        rust_module: textfield,
+    },
+}
@@ -1,22 +1,17 @@
-#[cfg(feature = "button")]
-pub mod button;
-#[cfg(feature = "card")]
-pub mod card;
-#[cfg(feature = "checkbox")]
-pub mod checkbox;
-#[cfg(feature = "chips")]
-pub mod chips;
-#[cfg(feature = "datepicker")]
-pub mod datepicker;
-#[cfg(feature = "elevation")]
-pub mod elevation;
-#[cfg(feature = "icon")]
-pub mod icon;
-#[cfg(feature = "iconbutton")]
-pub mod iconbutton;
-#[cfg(feature = "progress")]
-pub mod progress;
-#[cfg(feature = "select")]
-pub mod select;
-#[cfg(feature = "textfield")]
-pub mod textfield;
+// Declares the Rust module of each enabled feature in `manifest.rs`.
+macro_rules! component_manifest {
+    ($(
+        $feature:literal => {
+            modules: [$($material_module:literal),* $(,)?],
+            tags: [$($tag:literal),* $(,)?],
+            $(rust_module: $rust_module:ident,)?
+        }
+    ),* $(,)?) => {
+        $($(
+            #[cfg(feature = $feature)]
+            pub mod $rust_module;
+        )?)*
+    };
+}
+
+include!("manifest.rs");
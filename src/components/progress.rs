@@ -1,12 +1,119 @@
-use leptos::{component, view, IntoView};
+use std::cell::Cell;
+
+use futures::{Stream, StreamExt};
+use leptos::{
+    component, create_effect, create_rw_signal, spawn_local, view, IntoView, MaybeProp,
+    MaybeSignal, Signal, SignalGet, SignalSet, SignalUpdate,
+};
+
+use crate::lazy::load_component_module;
+
 /// [MWC Docs](https://material-web.dev/components/progress/)
 #[component]
-pub fn CircularProgress(#[prop(default = true)] indeterminate: bool) -> impl IntoView {
-    view! { <md-circular-progress indeterminate=indeterminate></md-circular-progress> }
+pub fn CircularProgress(
+    /// Progress out of `max`. While this is `None` the indicator is indeterminate, unless `indeterminate` is `false`.
+    #[prop(optional, into)]
+    value: MaybeProp<f64>,
+    /// The value at which the indicator is full. Defaults to 1.
+    #[prop(optional, into)]
+    max: MaybeProp<f64>,
+    /// Show an indeterminate animation. Defaults to whether `value` is `None`, so `false` without a `value`
+    /// shows an empty determinate indicator.
+    #[prop(optional, into)]
+    indeterminate: MaybeProp<bool>,
+    /// Cycle through four colors while indeterminate.
+    #[prop(optional, into)]
+    four_color: MaybeSignal<bool>,
+    /// Accessible name of the indicator, e.g. "Uploading photo".
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+) -> impl IntoView {
+    load_component_module("progress");
+    let is_indeterminate = move || indeterminate.get().unwrap_or_else(|| value.get().is_none());
+    view! {
+        <md-circular-progress
+            indeterminate=is_indeterminate
+            value=value
+            max=max
+            four-color=four_color
+            aria-label=aria_label
+        ></md-circular-progress>
+    }
 }
 
 /// [MWC Docs](https://material-web.dev/components/progress/)
 #[component]
-pub fn LinearProgress(#[prop(default = true)] indeterminate: bool) -> impl IntoView {
-    view! { <md-linear-progress indeterminate=indeterminate></md-linear-progress> }
+pub fn LinearProgress(
+    /// Progress out of `max`. While this is `None` the indicator is indeterminate, unless `indeterminate` is `false`.
+    #[prop(optional, into)]
+    value: MaybeProp<f64>,
+    /// The value at which the indicator is full. Defaults to 1.
+    #[prop(optional, into)]
+    max: MaybeProp<f64>,
+    /// Secondary progress out of `max`, e.g. how much of a video is buffered.
+    #[prop(optional, into)]
+    buffer: MaybeProp<f64>,
+    /// Show an indeterminate animation. Defaults to whether `value` is `None`, so `false` without a `value`
+    /// shows an empty determinate indicator.
+    #[prop(optional, into)]
+    indeterminate: MaybeProp<bool>,
+    /// Cycle through four colors while indeterminate.
+    #[prop(optional, into)]
+    four_color: MaybeSignal<bool>,
+    /// Accessible name of the indicator, e.g. "Uploading photo".
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+) -> impl IntoView {
+    load_component_module("progress");
+    let is_indeterminate = move || indeterminate.get().unwrap_or_else(|| value.get().is_none());
+    view! {
+        <md-linear-progress
+            indeterminate=is_indeterminate
+            value=value
+            max=max
+            buffer=buffer
+            four-color=four_color
+            aria-label=aria_label
+        ></md-linear-progress>
+    }
+}
+
+/// Track progress through a stream of byte counts, such as the chunks of an upload or download.
+/// Each item is the number of bytes in a chunk. The returned fraction, from 0 to 1, can be passed as a progress `value`.
+/// It is `None`, showing an indeterminate indicator, until the first chunk arrives or whenever `total_bytes` is unknown,
+/// and becomes 1 once the stream ends.
+///
+/// The stream is only read in the browser.
+/// ```ignore
+/// let progress = use_progress_from_stream(upload_chunks, Some(file.size() as u64));
+/// view! { <LinearProgress value=progress aria_label="Uploading"/> }
+/// ```
+pub fn use_progress_from_stream(
+    stream: impl Stream<Item = u64> + 'static,
+    total_bytes: Option<u64>,
+) -> Signal<Option<f64>> {
+    let loaded_bytes = create_rw_signal(None::<u64>);
+    let finished = create_rw_signal(false);
+    let stream = Cell::new(Some(stream));
+    // Effects only run in the browser, and this one only runs once since it reads no signals.
+    create_effect(move |_| {
+        if let Some(stream) = stream.take() {
+            spawn_local(async move {
+                let mut stream = std::pin::pin!(stream);
+                while let Some(bytes) = stream.next().await {
+                    loaded_bytes.update(|loaded| *loaded = Some(loaded.unwrap_or(0) + bytes));
+                }
+                finished.set(true);
+            });
+        }
+    });
+    Signal::derive(move || {
+        if finished.get() {
+            return Some(1.0);
+        }
+        let total_bytes = total_bytes.filter(|total| *total > 0)?;
+        loaded_bytes
+            .get()
+            .map(|loaded| (loaded as f64 / total_bytes as f64).min(1.0))
+    })
 }
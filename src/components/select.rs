@@ -1,9 +1,18 @@
 use leptos::{
-    component,
-    html::{self}, view, Attribute, Children, IntoView,
+    component, ev,
+    html::{self}, Attribute, Callback, Children, IntoAttribute, IntoView, MaybeProp, MaybeSignal,
+    RwSignal, SignalGet, SignalSet,
 };
 
-#[derive(Default)]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::events::{on_event, target_value};
+use crate::lazy::load_component_module;
+use crate::swap::swappable_element;
+
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum SelectStyle {
     #[default]
     Filled,
@@ -20,27 +29,254 @@ impl SelectStyle {
     }
 }
 
+/// How the options menu of a [`Select`] is positioned.
+#[derive(Clone, Copy, PartialEq)]
+pub enum MenuPositioning {
+    /// Shown in the top layer, above every other element.
+    Popover,
+    /// Positioned relative to the select. The menu is clipped by ancestors with `overflow: hidden`.
+    Absolute,
+    /// Positioned relative to the window, e.g. for selects in dialogs.
+    Fixed,
+}
+
+impl From<MenuPositioning> for String {
+    fn from(value: MenuPositioning) -> Self {
+        match value {
+            MenuPositioning::Popover => "popover",
+            MenuPositioning::Absolute => "absolute",
+            MenuPositioning::Fixed => "fixed",
+        }
+        .into()
+    }
+}
+
+impl IntoAttribute for MenuPositioning {
+    fn into_attribute(self) -> Attribute {
+        Attribute::String(String::from(self).into())
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        Attribute::String(String::from(*self).into())
+    }
+}
+
+/// Which edge of a [`Select`] its options menu is aligned with.
+#[derive(Clone, Copy, PartialEq)]
+pub enum MenuAlign {
+    /// The menu's start edge lines up with the select's, left in left-to-right languages.
+    Start,
+    End,
+}
+
+impl From<MenuAlign> for String {
+    fn from(value: MenuAlign) -> Self {
+        match value {
+            MenuAlign::Start => "start",
+            MenuAlign::End => "end",
+        }
+        .into()
+    }
+}
+
+impl IntoAttribute for MenuAlign {
+    fn into_attribute(self) -> Attribute {
+        Attribute::String(String::from(self).into())
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        Attribute::String(String::from(*self).into())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// The methods shared by `md-filled-select` and `md-outlined-select`.
+    #[wasm_bindgen(extends = web_sys::HtmlElement)]
+    type MdSelect;
+
+    #[wasm_bindgen(method)]
+    fn select(this: &MdSelect, value: &str);
+
+    #[wasm_bindgen(method, js_name = selectIndex)]
+    fn select_index(this: &MdSelect, index: u32);
+
+    #[wasm_bindgen(method)]
+    fn reset(this: &MdSelect);
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &MdSelect, error: &str);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &MdSelect) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &MdSelect) -> bool;
+}
+
+element_ref! {
+    /// Handle for calling methods on a [`Select`] element, passed with the `element_ref` prop.
+    /// Methods do nothing, and return `None`, while the select isn't mounted.
+    SelectRef(MdSelect)
+}
+
+impl SelectRef {
+    /// Select the first option with the given value.
+    pub fn select(&self, value: &str) {
+        if let Some(element) = self.element() {
+            element.select(value);
+        }
+    }
+
+    /// Select the option at `index`.
+    pub fn select_index(&self, index: u32) {
+        if let Some(element) = self.element() {
+            element.select_index(index);
+        }
+    }
+
+    /// Reset the selection to the options that were initially selected.
+    pub fn reset(&self) {
+        if let Some(element) = self.element() {
+            element.reset();
+        }
+    }
+
+    /// Mark the select invalid with `error` as its message. An empty string marks it valid.
+    pub fn set_custom_validity(&self, error: &str) {
+        if let Some(element) = self.element() {
+            element.set_custom_validity(error);
+        }
+    }
+
+    /// Whether the selection is valid, without showing an error.
+    pub fn check_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.check_validity())
+    }
+
+    /// Whether the selection is valid, showing its error message if it isn't.
+    pub fn report_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.report_validity())
+    }
+}
+
 /// [MWC Docs](https://material-web.dev/components/select/)
 #[component]
 pub fn Select(
-    #[prop(optional)] style: SelectStyle,
+    /// Changing the style swaps the element, keeping the select's options.
+    #[prop(optional, into)]
+    style: MaybeSignal<SelectStyle>,
     children: Children,
-    // #[prop(optional)] text_field_icon: Option<TextFieldIcon>,
-    // #[prop(optional)] props: Vec<TextFieldProperty>,
+    /// Bound to the value of the selected option. Updated when the user selects an option.
+    #[prop(optional)]
+    value: Option<RwSignal<String>>,
+    #[prop(optional, into)] label: MaybeProp<String>,
+    /// Text shown below the select.
+    #[prop(optional, into)]
+    supporting_text: MaybeProp<String>,
+    /// Show the select as invalid, with `error_text` replacing the supporting text.
+    #[prop(optional, into)]
+    error: MaybeSignal<bool>,
+    #[prop(optional, into)] error_text: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    #[prop(optional, into)] required: MaybeSignal<bool>,
+    /// Don't mark the label of a required select with an asterisk.
+    #[prop(optional, into)]
+    no_asterisk: MaybeSignal<bool>,
+    /// Name used when submitting the select with a form.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    /// Open and close the menu without animation.
+    #[prop(optional, into)]
+    quick: MaybeSignal<bool>,
+    /// Defaults to the element's positioning, which is `Popover` in current material-web versions.
+    #[prop(optional, into)]
+    menu_positioning: MaybeProp<MenuPositioning>,
+    /// Keep the menu from growing wider than the select.
+    #[prop(optional, into)]
+    clamp_menu_width: MaybeSignal<bool>,
+    /// Defaults to `Start`.
+    #[prop(optional, into)]
+    menu_align: MaybeProp<MenuAlign>,
+    /// Milliseconds after the last key press before typing to find an option starts over. Defaults to 200.
+    #[prop(optional, into)]
+    typeahead_delay: MaybeProp<u32>,
+    /// Leave room for an icon placed in the select's `leading-icon` slot.
+    #[prop(optional, into)]
+    has_leading_icon: MaybeSignal<bool>,
+    /// Text shown in the select before an option is selected. Replaced by the selected option's text.
+    #[prop(optional, into)]
+    display_text: MaybeProp<String>,
+    /// Any other attributes. These take precedence over the typed props above.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
+    /// Called with the value of the newly selected option.
+    #[prop(optional, into)]
+    on_change: Option<Callback<String>>,
+    /// Called once the options menu has finished opening.
+    #[prop(optional, into)]
+    on_opened: Option<Callback<()>>,
+    /// Called once the options menu has finished closing.
+    #[prop(optional, into)]
+    on_closed: Option<Callback<()>>,
+    #[prop(optional)] element_ref: SelectRef,
 ) -> impl IntoView {
-    html::custom(html::Custom::new(style.get_element_name())).child(children())
+    load_component_module("select");
+    let children = children();
+    swappable_element(style, SelectStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "label", label.clone())
+            .typed_attr(&attr, "supporting-text", supporting_text.clone())
+            .typed_attr(&attr, "error", error)
+            .typed_attr(&attr, "error-text", error_text.clone())
+            .typed_attr(&attr, "disabled", disabled)
+            .typed_attr(&attr, "required", required)
+            .typed_attr(&attr, "no-asterisk", no_asterisk)
+            .typed_attr(&attr, "name", name.clone())
+            .typed_attr(&attr, "quick", quick)
+            .typed_attr(&attr, "menu-positioning", menu_positioning)
+            .typed_attr(&attr, "clamp-menu-width", clamp_menu_width)
+            .typed_attr(&attr, "menu-align", menu_align)
+            .typed_attr(&attr, "typeahead-delay", typeahead_delay)
+            .typed_attr(&attr, "has-leading-icon", has_leading_icon)
+            .typed_attr(&attr, "display-text", display_text.clone())
+            .attrs(attr.clone())
+            .child(children.clone());
+        // The attribute makes the value part of server-rendered HTML, the property keeps it in sync after the user selects.
+        let html = match value {
+            Some(value) => html
+                .typed_attr(&attr, "value", move || value.get())
+                .prop("value", move || value.get())
+                .on(ev::change, move |event| value.set(target_value(&event))),
+            None => html,
+        };
+        let html = on_event(html, "change", on_change, target_value);
+        let html = on_event(html, "opened", on_opened, |_| ());
+        on_event(html, "closed", on_closed, |_| ())
+    })
 }
 
 /// [MWC Docs](https://material-web.dev/components/select/)
 #[component]
 pub fn SelectOption(
-    #[prop(into)] value: String,
+    #[prop(into)] value: MaybeSignal<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    /// Select this option initially.
+    #[prop(optional, into)]
+    selected: MaybeSignal<bool>,
+    /// Text shown in the select when this option is selected. Defaults to the option's text.
+    #[prop(optional, into)]
+    display_text: MaybeProp<String>,
     #[prop(attrs)] attr: Vec<(&'static str, Attribute)>,
     children: Children,
 ) -> impl IntoView {
-    view! {
-        <md-select-option value={ move || value }() {..attr}>
-            {children()}
-        </md-select-option>
-    }
+    load_component_module("select");
+    html::custom(html::Custom::new("md-select-option"))
+        .typed_attr(&attr, "value", value)
+        .typed_attr(&attr, "disabled", disabled)
+        .typed_attr(&attr, "selected", selected)
+        .typed_attr(&attr, "display-text", display_text)
+        .attrs(attr)
+        .child(children())
 }
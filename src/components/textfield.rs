@@ -1,11 +1,18 @@
 use leptos::{
-    component,
+    component, ev,
     html::{self},
-    slot, Attribute, Children, IntoAttribute, IntoView,
-    RwSignal, SignalGet,
+    slot, view, Attribute, Callback, Children, IntoAttribute, IntoView, MaybeProp, MaybeSignal,
+    RwSignal, SignalGet, SignalSet,
 };
 
-use super::icon::{Icon, IconProps};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use super::icon::{Icon, IconSource};
+use crate::attributes::TypedAttr;
+use crate::element_ref::element_ref;
+use crate::events::{on_event, target_value};
+use crate::lazy::load_component_module;
+use crate::swap::swappable_element;
 
 #[derive(PartialEq, Clone, Default)]
 pub enum InputType {
@@ -54,12 +61,12 @@ pub enum TextFieldIconPlacement {
 #[slot]
 pub struct TextFieldIcon {
     placement: TextFieldIconPlacement,
-    /// Icon Name
+    /// Icon name, or any other [`IconSource`].
     #[prop(into)]
-    name: String,
+    name: IconSource,
 }
 
-#[derive(Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum TextFieldStyle {
     #[default]
     Filled,
@@ -76,31 +83,197 @@ impl TextFieldStyle {
     }
 }
 
+#[wasm_bindgen]
+extern "C" {
+    /// The methods shared by `md-filled-text-field` and `md-outlined-text-field`.
+    #[wasm_bindgen(extends = web_sys::HtmlElement)]
+    type MdTextField;
+
+    #[wasm_bindgen(method)]
+    fn select(this: &MdTextField);
+
+    #[wasm_bindgen(method)]
+    fn reset(this: &MdTextField);
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &MdTextField, error: &str);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &MdTextField) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &MdTextField) -> bool;
+}
+
+element_ref! {
+    /// Handle for calling methods on a [`TextField`] element, passed with the `element_ref` prop.
+    /// Methods do nothing, and return `None`, while the field isn't mounted.
+    TextFieldRef(MdTextField)
+}
+
+impl TextFieldRef {
+    /// Select all of the field's text.
+    pub fn select(&self) {
+        if let Some(element) = self.element() {
+            element.select();
+        }
+    }
+
+    /// Reset the field to its default value.
+    pub fn reset(&self) {
+        if let Some(element) = self.element() {
+            element.reset();
+        }
+    }
+
+    /// Mark the field invalid with `error` as its message. An empty string marks it valid.
+    pub fn set_custom_validity(&self, error: &str) {
+        if let Some(element) = self.element() {
+            element.set_custom_validity(error);
+        }
+    }
+
+    /// Whether the field's value is valid, without showing an error.
+    pub fn check_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.check_validity())
+    }
+
+    /// Whether the field's value is valid, showing its error message if it isn't.
+    pub fn report_validity(&self) -> Option<bool> {
+        self.element().map(|element| element.report_validity())
+    }
+}
+
 /// [MWC Docs](https://material-web.dev/components/text-field/)
 #[component]
 pub fn TextField(
-    #[prop(optional)] style: TextFieldStyle,
-    #[prop(optional)] value: RwSignal<String>,
-    #[prop(attrs)] attr: Vec<(&'static str, Attribute)>,
+    /// Changing the style swaps the element, keeping the field's value and children.
+    #[prop(optional, into)]
+    style: MaybeSignal<TextFieldStyle>,
+    /// Bound to the field's value. Updated as the user types.
+    #[prop(optional)]
+    value: RwSignal<String>,
+    /// Any other attributes. These take precedence over the typed props below.
+    #[prop(attrs)]
+    attr: Vec<(&'static str, Attribute)>,
+    #[prop(optional, into)] input_type: MaybeSignal<InputType>,
+    #[prop(optional, into)] label: MaybeProp<String>,
+    #[prop(optional, into)] placeholder: MaybeProp<String>,
+    /// Text shown below the field.
+    #[prop(optional, into)]
+    supporting_text: MaybeProp<String>,
+    /// Text shown before the value, e.g. a currency symbol.
+    #[prop(optional, into)]
+    prefix_text: MaybeProp<String>,
+    /// Text shown after the value, e.g. a unit.
+    #[prop(optional, into)]
+    suffix_text: MaybeProp<String>,
+    /// Show the field as invalid, with `error_text` replacing the supporting text.
+    #[prop(optional, into)]
+    error: MaybeSignal<bool>,
+    #[prop(optional, into)] error_text: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeSignal<bool>,
+    #[prop(optional, into)] required: MaybeSignal<bool>,
+    /// Don't mark the label of a required field with an asterisk.
+    #[prop(optional, into)]
+    no_asterisk: MaybeSignal<bool>,
+    #[prop(optional, into)] readonly: MaybeSignal<bool>,
+    /// Name used when submitting the field with a form.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    #[prop(optional, into)] autocomplete: MaybeProp<String>,
+    /// Hint for which virtual keyboard to show, e.g. `numeric` or `email`.
+    #[prop(optional, into)]
+    input_mode: MaybeProp<String>,
+    /// Allow several comma separated addresses in an email field.
+    #[prop(optional, into)]
+    multiple: MaybeSignal<bool>,
+    /// Direction of the field's text, `ltr`, `rtl` or `auto`. Defaults to the document's direction.
+    #[prop(optional, into)]
+    text_direction: MaybeProp<String>,
+    #[prop(optional, into)] pattern: MaybeProp<String>,
+    #[prop(optional, into)] max_length: MaybeProp<i32>,
+    #[prop(optional, into)] min_length: MaybeProp<i32>,
+    /// Minimum value of a number field.
+    #[prop(optional, into)]
+    min: MaybeProp<String>,
+    /// Maximum value of a number field.
+    #[prop(optional, into)]
+    max: MaybeProp<String>,
+    #[prop(optional, into)] step: MaybeProp<String>,
+    /// Hide the increment and decrement buttons of a number field.
+    #[prop(optional, into)]
+    no_spinner: MaybeSignal<bool>,
+    /// Number of rows of a textarea.
+    #[prop(optional, into)]
+    rows: MaybeProp<u32>,
+    /// Number of columns of a textarea.
+    #[prop(optional, into)]
+    cols: MaybeProp<u32>,
     #[prop(optional)] text_field_icon: Option<TextFieldIcon>,
     #[prop(optional)] children: Option<Children>,
+    /// Called with the new value each time the user types.
+    #[prop(optional, into)]
+    on_input: Option<Callback<String>>,
+    /// Called with the new value when the user commits a change, e.g. by leaving the field.
+    #[prop(optional, into)]
+    on_change: Option<Callback<String>>,
+    #[prop(optional)] element_ref: TextFieldRef,
 ) -> impl IntoView {
-    let mut html = html::custom(html::Custom::new(style.get_element_name()))
-        .attrs(attr)
-        .prop("value", move || value.get());
-    if let Some(icon) = text_field_icon {
-        let icon_element = Icon(IconProps {
-            name: icon.name,
-            element_slot: match icon.placement {
-                TextFieldIconPlacement::Leading => "leading-icon",
-                TextFieldIconPlacement::Trailing => "trailing-icon",
-            }
-            .into(),
-        });
-        html = html.child(icon_element);
-    }
-    if let Some(child) = children {
-        html = html.child(child())
-    }
-    html
+    load_component_module("textfield");
+    let has_leading_icon = text_field_icon
+        .as_ref()
+        .is_some_and(|icon| matches!(icon.placement, TextFieldIconPlacement::Leading));
+    let has_trailing_icon = text_field_icon
+        .as_ref()
+        .is_some_and(|icon| matches!(icon.placement, TextFieldIconPlacement::Trailing));
+    let icon_element = text_field_icon.map(|icon| {
+        let element_slot = match icon.placement {
+            TextFieldIconPlacement::Leading => "leading-icon",
+            TextFieldIconPlacement::Trailing => "trailing-icon",
+        };
+        view! { <Icon name=icon.name element_slot=element_slot/> }
+    });
+    let children = children.map(|children| children());
+    swappable_element(style, TextFieldStyle::get_element_name, move |element_name| {
+        let html = html::custom(html::Custom::new(element_name))
+            .node_ref(element_ref.0)
+            .typed_attr(&attr, "type", input_type.clone())
+            .typed_attr(&attr, "label", label.clone())
+            .typed_attr(&attr, "placeholder", placeholder.clone())
+            .typed_attr(&attr, "supporting-text", supporting_text.clone())
+            .typed_attr(&attr, "prefix-text", prefix_text.clone())
+            .typed_attr(&attr, "suffix-text", suffix_text.clone())
+            .typed_attr(&attr, "error", error)
+            .typed_attr(&attr, "error-text", error_text.clone())
+            .typed_attr(&attr, "disabled", disabled)
+            .typed_attr(&attr, "required", required)
+            .typed_attr(&attr, "no-asterisk", no_asterisk)
+            .typed_attr(&attr, "readonly", readonly)
+            .typed_attr(&attr, "name", name.clone())
+            .typed_attr(&attr, "autocomplete", autocomplete.clone())
+            .typed_attr(&attr, "inputmode", input_mode.clone())
+            .typed_attr(&attr, "multiple", multiple)
+            .typed_attr(&attr, "textdirection", text_direction.clone())
+            .typed_attr(&attr, "pattern", pattern.clone())
+            .typed_attr(&attr, "maxlength", max_length)
+            .typed_attr(&attr, "minlength", min_length)
+            .typed_attr(&attr, "min", min.clone())
+            .typed_attr(&attr, "max", max.clone())
+            .typed_attr(&attr, "step", step.clone())
+            .typed_attr(&attr, "no-spinner", no_spinner)
+            .typed_attr(&attr, "rows", rows)
+            .typed_attr(&attr, "cols", cols)
+            .typed_attr(&attr, "has-leading-icon", has_leading_icon)
+            .typed_attr(&attr, "has-trailing-icon", has_trailing_icon)
+            .attrs(attr.clone())
+            // The attribute makes the value part of server-rendered HTML, the property keeps it in sync after the user types.
+            .typed_attr(&attr, "value", move || value.get())
+            .prop("value", move || value.get())
+            .on(ev::input, move |event| value.set(target_value(&event)))
+            .child(icon_element.clone())
+            .child(children.clone());
+        let html = on_event(html, "input", on_input, target_value);
+        on_event(html, "change", on_change, target_value)
+    })
 }
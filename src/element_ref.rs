@@ -0,0 +1,44 @@
+//! Handles for calling methods on a component's material-web element, passed with the component's `element_ref` prop.
+
+/// Define a handle type wrapping a `NodeRef` to a component's element, with the methods every element shares.
+/// `element()` casts the element to the given type, so each component's file can add the element's own methods.
+macro_rules! element_ref {
+    ($(#[$meta:meta])* $name:ident($element:ty)) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default)]
+        pub struct $name(leptos::NodeRef<leptos::html::Custom>);
+
+        impl $name {
+            pub fn new() -> Self {
+                Self(leptos::create_node_ref())
+            }
+
+            fn element(&self) -> Option<$element> {
+                self.0.get_untracked().map(|element| {
+                    wasm_bindgen::JsCast::unchecked_into((*element.into_any()).clone())
+                })
+            }
+
+            pub fn focus(&self) {
+                if let Some(element) = self.element() {
+                    let _ = element.focus();
+                }
+            }
+
+            pub fn blur(&self) {
+                if let Some(element) = self.element() {
+                    let _ = element.blur();
+                }
+            }
+
+            /// Click the element as if the user had, e.g. to submit a button's form or toggle a chip.
+            pub fn click(&self) {
+                if let Some(element) = self.element() {
+                    element.click();
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use element_ref;
@@ -0,0 +1,40 @@
+//! Helpers for reading material-web events, so components can expose typed callbacks instead of raw `on:` handlers.
+//! material-web elements aren't native inputs, so their properties are read by name rather than by casting
+//! the event target to an `HtmlInputElement`.
+use leptos::{ev, html::ElementDescriptor, Callable, Callback, HtmlElement};
+use web_sys::{js_sys::Reflect, wasm_bindgen::JsValue, Event};
+
+/// Read a property of the element that dispatched `event`.
+fn target_property(event: &Event, property: &str) -> JsValue {
+    event
+        .target()
+        .and_then(|target| Reflect::get(&target, &JsValue::from_str(property)).ok())
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+/// The `value` of the element that dispatched `event`, e.g. a text field or select.
+#[cfg(any(feature = "select", feature = "textfield"))]
+pub(crate) fn target_value(event: &Event) -> String {
+    target_property(event, "value").as_string().unwrap_or_default()
+}
+
+/// A boolean property, such as `checked` or `selected`, of the element that dispatched `event`.
+#[cfg(any(feature = "checkbox", feature = "chips", feature = "iconbutton"))]
+pub(crate) fn target_flag(event: &Event, property: &str) -> bool {
+    target_property(event, property).is_truthy()
+}
+
+/// Call `callback`, if there is one, with a value read from each `event_name` event.
+pub(crate) fn on_event<El: ElementDescriptor + 'static, T: 'static>(
+    element: HtmlElement<El>,
+    event_name: &'static str,
+    callback: Option<Callback<T>>,
+    read: impl Fn(&Event) -> T + 'static,
+) -> HtmlElement<El> {
+    match callback {
+        Some(callback) => element.on(ev::Custom::<Event>::new(event_name), move |event| {
+            callback.call(read(&event))
+        }),
+        None => element,
+    }
+}
@@ -0,0 +1,38 @@
+//! Runtime loader for the per-component modules built with the `lazy-loading` feature.
+//! Only features with a Rust module are split out. Without the feature, every component is already part of the bundle
+//! and loading is a no-op.
+
+#[cfg(feature = "lazy-loading")]
+mod loader {
+    use crate::external_bundle::MODULES_URL;
+    use leptos::create_effect;
+    use std::{cell::RefCell, collections::HashSet};
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(inline_js = "export function import_module(url) { return import(url); }")]
+    extern "C" {
+        fn import_module(url: &str) -> JsValue;
+    }
+
+    thread_local! {
+        static REQUESTED_MODULES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+
+    pub fn load_component_module(feature: &'static str) {
+        // Effects only run in the browser, so nothing is imported while rendering on the server.
+        create_effect(move |_| {
+            let first_request = REQUESTED_MODULES.with(|modules| modules.borrow_mut().insert(feature));
+            if first_request {
+                let _ = import_module(&format!("{}/{}.js", MODULES_URL, feature));
+            }
+        });
+    }
+}
+
+/// Import the material-web elements for the component `feature` the first time one of its components is rendered.
+pub(crate) fn load_component_module(feature: &'static str) {
+    #[cfg(feature = "lazy-loading")]
+    loader::load_component_module(feature);
+    #[cfg(not(feature = "lazy-loading"))]
+    let _ = feature;
+}
@@ -2,33 +2,95 @@
 //! This library aims to stay as close to the original MWC component specs, meaning you should be able to use everything
 //! as specified in their [docs](https://material-web.dev/).
 //! leptos-material also tries to fill in the gaps for components that have not yet been implemented in material-web, such as Cards and Date Pickers.
-//! Many components have a wide variety of attributes supported. For brevity, leptos-material does not completely expose all attributes,
-//! instead, a component may support using `attr:attribute_name=value` syntax in the `view` macro. For example:
-//! ```
+//! Components expose the attributes of their material-web element as typed props. For example:
+//! ```ignore
 //! <TextField
 //! style=TextFieldStyle::Outlined
-//! attr:type=InputType::Email
-//! attr:label="Email"
+//! input_type=InputType::Email
+//! label="Email"
 //! />
 //! ```
+//! Anything not covered by a typed prop can still be set with `attr:attribute_name=value` syntax in the `view` macro,
+//! and such attributes take precedence over the typed props.
 
 #![allow(non_snake_case)] // allow camel case component names
 use leptos::{view, IntoView};
 use leptos_meta::*;
 pub mod components;
+// Helpers shared by components, compiled with the component features that use them.
+#[cfg(any(
+    feature = "button",
+    feature = "card",
+    feature = "chips",
+    feature = "fab",
+    feature = "iconbutton",
+    feature = "select",
+    feature = "textfield"
+))]
+mod attributes;
+#[cfg(any(
+    feature = "checkbox",
+    feature = "chips",
+    feature = "iconbutton",
+    feature = "select",
+    feature = "textfield"
+))]
+mod events;
+#[cfg(any(
+    feature = "button",
+    feature = "checkbox",
+    feature = "chips",
+    feature = "fab",
+    feature = "iconbutton",
+    feature = "select",
+    feature = "textfield"
+))]
+mod element_ref;
+#[cfg(any(
+    feature = "button",
+    feature = "checkbox",
+    feature = "chips",
+    feature = "elevation",
+    feature = "fab",
+    feature = "icon",
+    feature = "iconbutton",
+    feature = "progress",
+    feature = "select",
+    feature = "textfield"
+))]
+mod lazy;
+#[cfg(any(feature = "button", feature = "card", feature = "chips", feature = "fab"))]
+mod link;
+#[cfg(any(
+    feature = "button",
+    feature = "chips",
+    feature = "fab",
+    feature = "iconbutton",
+    feature = "select",
+    feature = "textfield"
+))]
+mod swap;
+
+#[cfg(feature = "external-bundle")]
+mod external_bundle {
+    include!(concat!(env!("OUT_DIR"), "/external_bundle.rs"));
+}
 
 /// Add required stylesheets and script tags to the page \<head\>.
 /// This must be placed at the root of your main component for leptos-material components to work as intended.
 /// For example, if you have a main component named `App`, your code will look like this:
-/// ```
+/// ```ignore
 /// #[component]
 /// fn App() -> impl IntoView {
-/// 	view! {
-/// 		<UseMaterialWebComponents/>
-/// 		... // The rest of your site
-/// 	}
+///     view! {
+///         <UseMaterialWebComponents/>
+///         ... // The rest of your site
+///     }
 /// }
 /// ```
+///
+/// With the `external-bundle` feature, the material-web bundle is linked with a `<script src>` and an `integrity` hash
+/// instead of being inlined into every page. Enable Leptos' `nonce` feature to add a CSP nonce to the generated tags.
 pub fn UseMaterialWebComponents() -> impl IntoView {
     provide_meta_context();
     view! {
@@ -38,13 +100,29 @@ pub fn UseMaterialWebComponents() -> impl IntoView {
         />
         <Link
             rel="stylesheet"
-            href="https://fonts.googleapis.com/css2?family=Material+Symbols+Outlined"
+            // Every axis of every icon family. Browsers only download a family's font once an icon uses it.
+            href="https://fonts.googleapis.com/css2?family=Material+Symbols+Outlined:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200&family=Material+Symbols+Rounded:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200&family=Material+Symbols+Sharp:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200"
         />
         <Style>"body { font-family = 'Roboto'; }"</Style>
-        <Script>{include_str!(concat!(env!("OUT_DIR"), "/output_bundle.js"))}</Script>
+        {#[cfg(not(feature = "external-bundle"))]
+        view! { <Script>{include_str!(concat!(env!("OUT_DIR"), "/output_bundle.js"))}</Script> }}
+        {#[cfg(feature = "external-bundle")]
+        view! {
+            <Script
+                src=external_bundle::BUNDLE_URL
+                integrity=external_bundle::BUNDLE_INTEGRITY
+                crossorigin="anonymous"
+            />
+        }}
+        // Elements without a leptos-material component, built with the lazy modules so they share chunks with them.
+        {#[cfg(feature = "lazy-loading")]
+        external_bundle::EAGER_MODULE_URL.map(|src| view! { <Script type_="module" src=src/> })}
         <Style>
 
+            {#[cfg(feature = "button")] include_str!("components/css/button.css")}
             {#[cfg(feature = "card")] include_str!("components/css/card.css")}
+            {#[cfg(feature = "checkbox")] include_str!("components/css/checkbox.css")}
+            {#[cfg(feature = "chips")] include_str!("components/css/chips.css")}
             {#[cfg(feature = "datepicker")] include_str!("components/css/datepicker.css")}
 
         </Style>
@@ -0,0 +1,121 @@
+//! Link behaviour for components with an `href`, such as buttons, FABs and chips.
+//! With the `router` feature, links inside a `leptos_router` `<Router>` navigate client-side like `<A>`,
+//! are marked with `aria-current="page"` while their route is active, and are prefetched on hover.
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use leptos::{
+    document, ev, html::ElementDescriptor, window, Attribute, HtmlElement, MaybeProp, Signal,
+    SignalGet, SignalGetUntracked,
+};
+
+use crate::attributes::TypedAttr;
+
+/// Links to other sites, or with a scheme such as `mailto:`, are left to the browser.
+fn is_internal(href: &str) -> bool {
+    (href.starts_with('/') && !href.starts_with("//")) || !href.contains(':')
+}
+
+thread_local! {
+    static PREFETCHED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// Ask the browser to fetch `href` in the background, once per URL.
+fn prefetch(href: &str) {
+    let first_request = PREFETCHED.with(|prefetched| prefetched.borrow_mut().insert(href.to_string()));
+    if !first_request {
+        return;
+    }
+    if let (Ok(link), Some(head)) = (document().create_element("link"), document().head()) {
+        let _ = link.set_attribute("rel", "prefetch");
+        let _ = link.set_attribute("href", href);
+        let _ = head.append_child(&link);
+    }
+}
+
+type Navigate = Rc<dyn Fn(&str)>;
+
+#[cfg(feature = "router")]
+fn router_link(href: MaybeProp<String>) -> Option<(Navigate, Signal<bool>)> {
+    use leptos::use_context;
+    use leptos_router::{use_location, use_navigate, use_resolved_path, RouterContext};
+
+    use_context::<RouterContext>()?;
+    let navigate = use_navigate();
+    let pathname = use_location().pathname;
+    let has_href = href.clone();
+    let resolved = use_resolved_path(move || href.get().unwrap_or_default());
+    // A missing href would resolve to the current route, so only elements with an href can be active.
+    let is_active = Signal::derive(move || {
+        has_href.get().is_some()
+            && resolved.get().is_some_and(|resolved| {
+                let resolved = resolved.split(['?', '#']).next().unwrap_or_default();
+                pathname.get() == resolved
+            })
+    });
+    Some((Rc::new(move |href: &str| navigate(href, Default::default())), is_active))
+}
+
+#[cfg(not(feature = "router"))]
+fn router_link(_href: MaybeProp<String>) -> Option<(Navigate, Signal<bool>)> {
+    None
+}
+
+/// Make `element` a link to `href`.
+/// Set `renders_anchor` for elements that render their own `<a>` when given an `href`, such as `md-filled-button`;
+/// other elements, such as `md-fab`, are given the `link` role and navigate on click.
+/// Attributes in `overrides`, the component's `attr:` attributes, aren't set.
+pub(crate) fn link<El: ElementDescriptor + 'static>(
+    element: HtmlElement<El>,
+    href: MaybeProp<String>,
+    target: MaybeProp<String>,
+    renders_anchor: bool,
+    overrides: &[(&'static str, Attribute)],
+) -> HtmlElement<El> {
+    let router = router_link(href.clone());
+    let is_active = router.as_ref().map(|(_, is_active)| *is_active);
+    let navigate = router.map(|(navigate, _)| navigate);
+    let has_href = href.clone();
+    let element = if renders_anchor {
+        element
+            .typed_attr(overrides, "href", href.clone())
+            .typed_attr(overrides, "target", target.clone())
+    } else {
+        element.typed_attr(overrides, "role", move || has_href.get().map(|_| "link"))
+    };
+    let prefetch_href = href.clone();
+    let has_router = navigate.is_some();
+    element
+        .typed_attr(overrides, "aria-current", move || {
+            is_active.is_some_and(|is_active| is_active.get()).then_some("page")
+        })
+        .on(ev::click, move |event| {
+            let Some(href) = href.get_untracked() else {
+                return;
+            };
+            let modified = event.meta_key() || event.ctrl_key() || event.shift_key() || event.alt_key();
+            if event.default_prevented() || event.button() != 0 || modified {
+                return;
+            }
+            let target = target.get_untracked().filter(|target| target != "_self");
+            match (&navigate, target) {
+                (_, Some(target)) if !renders_anchor => {
+                    let _ = window().open_with_url_and_target(&href, &target);
+                }
+                (Some(navigate), None) if is_internal(&href) => {
+                    event.prevent_default();
+                    navigate(&href);
+                }
+                _ if !renders_anchor => {
+                    let _ = window().location().set_href(&href);
+                }
+                _ => {}
+            }
+        })
+        // Only pages the router would navigate to are prefetched, so hovering never sends requests to other sites.
+        .on(ev::pointerenter, move |_| {
+            let href = prefetch_href.get_untracked();
+            if let Some(href) = href.filter(|href| has_router && is_internal(href)) {
+                prefetch(&href);
+            }
+        })
+}
@@ -0,0 +1,28 @@
+//! Components whose material-web element depends on a prop, such as a `Button`'s style choosing between
+//! `md-filled-button` and `md-text-button`.
+use leptos::{
+    create_memo,
+    html::{self, Custom},
+    HtmlElement, IntoView, MaybeSignal, SignalGet, View,
+};
+
+/// Render the element `build` creates with the tag `element_name` gives for `variant`.
+/// A component's `on:` handlers are attached to the view it returns, so a reactive `variant` wraps the element in a
+/// `display: contents` span which keeps the handlers while the element is rebuilt with another tag.
+/// Only events that bubble, such as `click` or `input`, reach the span. A static `variant` renders the element alone.
+pub(crate) fn swappable_element<V: Clone + PartialEq + 'static>(
+    variant: MaybeSignal<V>,
+    element_name: fn(&V) -> String,
+    build: impl Fn(String) -> HtmlElement<Custom> + 'static,
+) -> View {
+    match variant {
+        MaybeSignal::Static(variant) => build(element_name(&variant)).into_view(),
+        MaybeSignal::Dynamic(variant) => {
+            let element_name = create_memo(move |_| element_name(&variant.get()));
+            html::span()
+                .attr("style", "display: contents")
+                .child(move || build(element_name.get()))
+                .into_view()
+        }
+    }
+}
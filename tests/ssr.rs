@@ -0,0 +1,566 @@
+//! Renders each component to a string, as a server does, and checks the HTML matches what the page hydrates to.
+//! Run with `cargo test --features ssr`, with the default `full` feature.
+#![cfg(all(feature = "ssr", feature = "full"))]
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use leptos::{
+    create_node_ref, create_rw_signal, html, ssr::render_to_string, view, DynAttrs, IntoView,
+};
+use leptos_material::components::{
+    button::{Button, ButtonStyle},
+    card::{Card, CardStyle, Headline},
+    checkbox::{Checkbox, CheckboxGroup, CheckboxState},
+    chips::{Chip, ChipType, ChipsField, Chipset, FilterChipSet, InputChipSet},
+    datepicker::{DatePicker, DatePickerMode},
+    elevation::Elevation,
+    fab::Fab,
+    icon::{Icon, IconFamily},
+    iconbutton::{IconButton, ToggleIconButton},
+    progress::{CircularProgress, LinearProgress},
+    select::{MenuAlign, MenuPositioning, Select, SelectOption},
+    textfield::{TextField, TextFieldStyle},
+};
+
+fn render<N: IntoView>(view: impl FnOnce() -> N + 'static) -> String {
+    render_to_string(view).to_string()
+}
+
+/// The opening tags, up to but excluding `>`, of every `tag` element in `html`.
+fn opening_tags<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    html.match_indices(&format!("<{} ", tag))
+        .chain(html.match_indices(&format!("<{}>", tag)))
+        .map(|(start, _)| {
+            let end = html[start..]
+                .find('>')
+                .map_or(html.len(), |end| start + end);
+            &html[start..end]
+        })
+        .collect()
+}
+
+/// Whether a `tag` element in `html` has `attribute`, either a name such as `checked`, or a name and value such as `value="a"`.
+fn has_attribute(html: &str, tag: &str, attribute: &str) -> bool {
+    opening_tags(html, tag).into_iter().any(|opening_tag| {
+        if attribute.contains('=') {
+            opening_tag.contains(&format!(" {}", attribute))
+        } else {
+            opening_tag
+                .split_whitespace()
+                .any(|token| token.split('=').next() == Some(attribute))
+        }
+    })
+}
+
+#[test]
+fn button() {
+    let html =
+        render(|| view! { <Button disabled=true name="action" value="save">"Save"</Button> });
+    assert!(html.contains("<md-elevated-button"), "{html}");
+    assert!(
+        has_attribute(&html, "md-elevated-button", "disabled"),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"value="save""#),
+        "{html}"
+    );
+    assert!(html.contains("Save"), "{html}");
+}
+
+#[test]
+fn reactive_style_wraps_element() {
+    let html = render(|| {
+        let style = create_rw_signal(ButtonStyle::Text);
+        view! { <Button style=style>"Save"</Button> }
+    });
+    assert!(
+        html.contains(r#"<span style="display: contents""#),
+        "{html}"
+    );
+    assert!(html.contains("<md-text-button"), "{html}");
+}
+
+#[test]
+fn static_style_renders_element_alone() {
+    let html = render(|| view! { <Button style=ButtonStyle::Text>"Save"</Button> });
+    assert!(html.contains("<md-text-button"), "{html}");
+    assert!(!html.contains("<span"), "{html}");
+}
+
+#[test]
+fn button_loading() {
+    let html = render(|| view! { <Button loading=true>"Save"</Button> });
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"aria-busy="true""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-elevated-button", "disabled"),
+        "{html}"
+    );
+    assert!(html.contains("<md-circular-progress"), "{html}");
+}
+
+#[test]
+fn attr_overrides_typed_props() {
+    let html = render(
+        || view! { <Button disabled=true attr:disabled=false soft_disabled=true>"Save"</Button> },
+    );
+    assert!(
+        !has_attribute(&html, "md-elevated-button", "disabled"),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-elevated-button", "soft-disabled"),
+        "{html}"
+    );
+    let html = render(|| view! { <Button loading=true attr:class="wide">"Save"</Button> });
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"class="wide""#),
+        "{html}"
+    );
+    assert_eq!(
+        opening_tags(&html, "md-elevated-button")[0]
+            .matches("class=")
+            .count(),
+        1,
+        "{html}"
+    );
+}
+
+#[test]
+fn link_button() {
+    let html = render(|| view! { <Button href="/settings" target="_blank">"Settings"</Button> });
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"href="/settings""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"target="_blank""#),
+        "{html}"
+    );
+}
+
+/// Render `view` inside a `leptos_router` `<Router>` at `path`.
+#[cfg(feature = "router")]
+fn render_in_router<N: IntoView>(path: &'static str, view: impl Fn() -> N + 'static) -> String {
+    use leptos::provide_context;
+    use leptos_router::{RequestUrl, Router};
+
+    render(move || {
+        provide_context(RequestUrl::new(path));
+        view! { <Router>{view()}</Router> }
+    })
+}
+
+#[cfg(feature = "router")]
+#[test]
+fn router_marks_only_links_to_the_current_page() {
+    let html = render_in_router("/settings", || view! { <Button>"Save"</Button> });
+    assert!(
+        !has_attribute(&html, "md-elevated-button", "aria-current"),
+        "{html}"
+    );
+    let html = render_in_router(
+        "/settings",
+        || view! { <Button href="/settings">"Settings"</Button> },
+    );
+    assert!(
+        has_attribute(&html, "md-elevated-button", r#"aria-current="page""#),
+        "{html}"
+    );
+}
+
+#[test]
+fn icon_button() {
+    let html =
+        render(|| view! { <IconButton aria_label="Delete"><Icon name="delete"/></IconButton> });
+    assert!(
+        has_attribute(&html, "md-icon-button", r#"aria-label="Delete""#),
+        "{html}"
+    );
+    assert!(html.contains("<md-icon"), "{html}");
+}
+
+#[test]
+fn toggle_icon_button() {
+    let html = render(|| {
+        let selected = create_rw_signal(true);
+        view! { <ToggleIconButton selected=selected icon="favorite" selected_icon="favorite"/> }
+    });
+    assert!(has_attribute(&html, "md-icon-button", "toggle"), "{html}");
+    assert!(has_attribute(&html, "md-icon-button", "selected"), "{html}");
+}
+
+#[test]
+fn fab() {
+    let html = render(|| view! { <Fab label="Compose" aria_label="Compose"/> });
+    assert!(
+        has_attribute(&html, "md-fab", r#"label="Compose""#),
+        "{html}"
+    );
+}
+
+#[test]
+fn icon() {
+    let html = render(|| view! { <Icon name="home" family=IconFamily::Rounded weight=600_u16/> });
+    assert!(html.contains("<md-icon"), "{html}");
+    assert!(
+        html.contains("--md-icon-font: 'Material Symbols Rounded';"),
+        "{html}"
+    );
+    assert!(
+        html.contains("font-variation-settings: 'wght' 600;"),
+        "{html}"
+    );
+    assert!(html.contains("home"), "{html}");
+    // Without axes or a family, a theme's icon font and settings aren't overridden.
+    let html = render(|| view! { <Icon name="home"/> });
+    assert!(!has_attribute(&html, "md-icon", "style"), "{html}");
+}
+
+#[test]
+fn progress() {
+    let html = render(|| view! { <CircularProgress value=0.5/> });
+    assert!(
+        has_attribute(&html, "md-circular-progress", r#"value="0.5""#),
+        "{html}"
+    );
+    assert!(
+        !has_attribute(&html, "md-circular-progress", "indeterminate"),
+        "{html}"
+    );
+    let html = render(|| view! { <LinearProgress/> });
+    assert!(
+        has_attribute(&html, "md-linear-progress", "indeterminate"),
+        "{html}"
+    );
+}
+
+#[test]
+fn progress_explicitly_determinate_without_value() {
+    let html = render(|| view! { <LinearProgress indeterminate=false/> });
+    assert!(
+        !has_attribute(&html, "md-linear-progress", "indeterminate"),
+        "{html}"
+    );
+    let html = render(|| view! { <CircularProgress value=0.5 indeterminate=true/> });
+    assert!(
+        has_attribute(&html, "md-circular-progress", "indeterminate"),
+        "{html}"
+    );
+}
+
+#[test]
+fn text_field() {
+    let html = render(|| {
+        let value = create_rw_signal("Ada".to_string());
+        view! { <TextField style=TextFieldStyle::Outlined value=value label="Name" required=true/> }
+    });
+    assert!(
+        has_attribute(&html, "md-outlined-text-field", r#"value="Ada""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-outlined-text-field", r#"label="Name""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-outlined-text-field", "required"),
+        "{html}"
+    );
+}
+
+#[test]
+fn text_field_input_attributes() {
+    let html = render(|| {
+        view! {
+            <TextField
+                required=true
+                no_asterisk=true
+                input_mode="numeric"
+                multiple=true
+                text_direction="rtl"
+            />
+        }
+    });
+    for attribute in [
+        "no-asterisk",
+        r#"inputmode="numeric""#,
+        "multiple",
+        r#"textdirection="rtl""#,
+    ] {
+        assert!(
+            has_attribute(&html, "md-filled-text-field", attribute),
+            "{attribute}: {html}"
+        );
+    }
+}
+
+#[test]
+fn select() {
+    let html = render(|| {
+        view! {
+            <Select label="Size">
+                <SelectOption value="s">"Small"</SelectOption>
+                <SelectOption value="m" selected=true>"Medium"</SelectOption>
+            </Select>
+        }
+    });
+    assert!(
+        has_attribute(&html, "md-filled-select", r#"label="Size""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-select-option", r#"value="m""#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"<md-select-option value="m" selected"#),
+        "{html}"
+    );
+    assert!(
+        !html.contains(r#"<md-select-option value="s" selected"#),
+        "{html}"
+    );
+}
+
+#[test]
+fn select_menu_attributes() {
+    let html = render(|| {
+        view! {
+            <Select
+                menu_align=MenuAlign::End
+                typeahead_delay=500_u32
+                has_leading_icon=true
+                display_text="Pick a size"
+            >
+                <SelectOption value="s">"Small"</SelectOption>
+            </Select>
+        }
+    });
+    for attribute in [
+        r#"menu-align="end""#,
+        r#"typeahead-delay="500""#,
+        "has-leading-icon",
+        r#"display-text="Pick a size""#,
+    ] {
+        assert!(
+            has_attribute(&html, "md-filled-select", attribute),
+            "{attribute}: {html}"
+        );
+    }
+}
+
+#[test]
+fn select_value() {
+    let html = render(|| {
+        let value = create_rw_signal("m".to_string());
+        view! {
+            <Select value=value menu_positioning=MenuPositioning::Fixed clamp_menu_width=true>
+                <SelectOption value="s">"Small"</SelectOption>
+                <SelectOption value="m">"Medium"</SelectOption>
+            </Select>
+        }
+    });
+    assert!(
+        has_attribute(&html, "md-filled-select", r#"value="m""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-filled-select", r#"menu-positioning="fixed""#),
+        "{html}"
+    );
+    assert!(
+        has_attribute(&html, "md-filled-select", "clamp-menu-width"),
+        "{html}"
+    );
+}
+
+#[test]
+fn checkbox() {
+    let html = render(|| {
+        let checked = create_rw_signal(true);
+        view! { <Checkbox get_checked=checked set_checked=checked/> }
+    });
+    assert!(has_attribute(&html, "md-checkbox", "checked"), "{html}");
+    let html = render(|| {
+        let checked = create_rw_signal(false);
+        view! { <Checkbox get_checked=checked set_checked=checked label="Remember me"/> }
+    });
+    assert!(!has_attribute(&html, "md-checkbox", "checked"), "{html}");
+    assert!(html.contains("Remember me"), "{html}");
+}
+
+#[test]
+fn tri_state_checkbox() {
+    let html = render(|| {
+        let state = create_rw_signal(CheckboxState::Indeterminate);
+        view! { <Checkbox state=state/> }
+    });
+    assert!(
+        has_attribute(&html, "md-checkbox", "indeterminate"),
+        "{html}"
+    );
+    assert!(!has_attribute(&html, "md-checkbox", "checked"), "{html}");
+}
+
+#[test]
+fn checkbox_group() {
+    let html = render(|| {
+        let selected = create_rw_signal(HashSet::from(["Cheese"]));
+        view! {
+            <CheckboxGroup
+                selected=selected
+                options=vec!["Cheese", "Olives"]
+                option_label=|option: &str| option.to_string()
+                select_all_label="All toppings"
+            />
+        }
+    });
+    assert_eq!(html.matches("<md-checkbox").count(), 3, "{html}");
+    assert_eq!(html.matches("<md-checkbox checked").count(), 1, "{html}");
+    assert!(
+        has_attribute(&html, "md-checkbox", "indeterminate"),
+        "{html}"
+    );
+}
+
+#[test]
+fn chips() {
+    let html = render(|| {
+        view! {
+            <Chipset>
+                <Chip chip_type=ChipType::Filter label="Open" selected=true/>
+                <Chip chip_type=ChipType::Assist label="Help" href="/help"/>
+            </Chipset>
+        }
+    });
+    assert!(html.contains("<md-chip-set"), "{html}");
+    assert!(has_attribute(&html, "md-filter-chip", "selected"), "{html}");
+    assert!(
+        has_attribute(&html, "md-assist-chip", r#"href="/help""#),
+        "{html}"
+    );
+}
+
+#[test]
+fn chip_sets() {
+    let html = render(|| {
+        let selected = create_rw_signal(HashSet::from(["M"]));
+        view! { <FilterChipSet selected=selected options=vec!["S", "M"] option_label=|size: &str| size.to_string()/> }
+    });
+    assert_eq!(html.matches("<md-filter-chip").count(), 2, "{html}");
+    assert_eq!(html.matches(" selected").count(), 1, "{html}");
+    let html = render(|| {
+        let items = create_rw_signal(vec!["rust".to_string()]);
+        view! { <InputChipSet items=items item_label=|item: String| item/> }
+    });
+    assert!(
+        has_attribute(&html, "md-input-chip", r#"label="rust""#),
+        "{html}"
+    );
+}
+
+#[test]
+fn chips_field() {
+    let html = render(|| {
+        let values = create_rw_signal(vec!["ada@example.com".to_string()]);
+        view! { <ChipsField values=values label="To"/> }
+    });
+    assert!(
+        has_attribute(&html, "md-input-chip", r#"label="ada@example.com""#),
+        "{html}"
+    );
+    assert!(html.contains("<md-filled-text-field"), "{html}");
+    // The text field's input is in its shadow root, so the suggestions take focus instead of being referenced by id.
+    for attribute in ["role=", "aria-activedescendant", "aria-controls"] {
+        assert!(
+            !has_attribute(&html, "md-filled-text-field", attribute),
+            "{html}"
+        );
+    }
+}
+
+#[test]
+fn card() {
+    let html = render(|| {
+        view! {
+            <Card style=CardStyle::Outlined>
+                <Headline slot>"Headline"</Headline>
+                "Content"
+            </Card>
+        }
+    });
+    assert!(html.contains("leptos-material-card outlined"), "{html}");
+    assert!(html.contains("<md-elevation"), "{html}");
+    assert!(
+        html.contains("Headline") && html.contains("Content"),
+        "{html}"
+    );
+}
+
+#[test]
+fn card_level() {
+    let html =
+        render(|| view! { <Card style=CardStyle::Filled level=4 disabled=true>"Album"</Card> });
+    assert!(html.contains("--md-elevation-level: 4"), "{html}");
+}
+
+#[test]
+fn link_card() {
+    let html = render(|| view! { <Card style=CardStyle::Filled href="/albums/1">"Album"</Card> });
+    assert!(has_attribute(&html, "div", r#"role="link""#), "{html}");
+    assert!(has_attribute(&html, "div", r#"tabindex="0""#), "{html}");
+}
+
+#[test]
+fn elevation() {
+    let html = render(|| view! { <Elevation level=3/> });
+    assert!(html.contains("--md-elevation-level: 3"), "{html}");
+}
+
+#[test]
+fn date_picker() {
+    let html = render(|| {
+        let value = create_rw_signal(NaiveDate::from_ymd_opt(2026, 1, 15));
+        view! { <DatePicker mode=DatePickerMode::Inline value=value on_confirm=|_| {}/> }
+    });
+    assert!(html.contains(r#"role="grid""#), "{html}");
+    assert!(html.contains(r#"aria-selected="true""#), "{html}");
+    assert!(html.contains("Thursday, January 15, 2026"), "{html}");
+}
+
+#[test]
+fn date_picker_days_outside_month() {
+    // January 2026 starts on a Thursday and December 2026 ends on a Thursday.
+    let html = render(|| {
+        let value = create_rw_signal(NaiveDate::from_ymd_opt(2026, 1, 15));
+        view! { <DatePicker mode=DatePickerMode::Inline value=value on_confirm=|_| {}/> }
+    });
+    assert!(html.contains("Sunday, December 28, 2025"), "{html}");
+    let html = render(|| {
+        let value = create_rw_signal(NaiveDate::from_ymd_opt(2026, 12, 15));
+        view! { <DatePicker mode=DatePickerMode::Inline value=value on_confirm=|_| {}/> }
+    });
+    assert!(html.contains("Saturday, January 2, 2027"), "{html}");
+}
+
+#[test]
+fn date_picker_popover() {
+    let html = render(|| {
+        let anchor = create_node_ref::<html::Div>();
+        let active = create_rw_signal(true);
+        view! {
+            <div node_ref=anchor></div>
+            <DatePicker mode=DatePickerMode::Popover(anchor.into()) active=active on_confirm=|_| {}/>
+        }
+    });
+    assert!(
+        html.contains("leptos-material-datepicker-popover"),
+        "{html}"
+    );
+    assert!(html.contains(r#"role="grid""#), "{html}");
+}
@@ -0,0 +1,23 @@
+//! Checks `Symbol` is generated with the Material Symbols codepoints, which differ from the older Material Icons font's for some icons.
+#![cfg(feature = "icon")]
+
+use leptos_material::{components::icon::Symbol, icon};
+
+#[test]
+fn symbols_have_material_symbols_codepoints() {
+    for (symbol, codepoint) in [
+        (icon!(home), '\u{e9b2}'),
+        (icon!(delete), '\u{e92e}'),
+        (icon!(search), '\u{e8b6}'),
+        (icon!(chevron_left), '\u{e5cb}'),
+        (icon!(drag_indicator), '\u{e945}'),
+    ] {
+        assert_eq!(symbol.codepoint(), codepoint, "{}", symbol.name());
+    }
+}
+
+#[test]
+fn symbols_are_found_by_name() {
+    assert_eq!(Symbol::from_name("home"), Some(Symbol::Home));
+    assert_eq!(Symbol::from_name("not_an_icon"), None);
+}
@@ -0,0 +1,37 @@
+//! Mounts components in a browser and checks how they behave once the page is interactive.
+//! Run with `wasm-pack test --headless --firefox`.
+#![cfg(all(target_arch = "wasm32", feature = "full"))]
+
+use leptos::{
+    create_rw_signal, document, mount_to_body, view, SignalGetUntracked, SignalSet, SignalUpdate,
+};
+use leptos_material::components::button::{Button, ButtonStyle};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn element(selector: &str) -> web_sys::HtmlElement {
+    document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap_or_else(|| panic!("no {selector} element"))
+        .unchecked_into()
+}
+
+#[wasm_bindgen_test]
+fn click_handler_survives_style_change() {
+    let style = create_rw_signal(ButtonStyle::Filled);
+    let clicks = create_rw_signal(0);
+    mount_to_body(move || {
+        view! {
+            <Button style=style on:click=move |_| clicks.update(|clicks| *clicks += 1)>
+                "Save"
+            </Button>
+        }
+    });
+    element("md-filled-button").click();
+    style.set(ButtonStyle::Text);
+    element("md-text-button").click();
+    assert_eq!(clicks.get_untracked(), 2);
+}